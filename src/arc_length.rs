@@ -0,0 +1,305 @@
+use crate::{Bar, Error, Model};
+use russell_lab::{Matrix, Norm, Vector, solve_lin_sys, vec_inner, vec_norm};
+
+/// Holds the results of one step of the arc-length method
+#[derive(Clone, Debug)]
+pub struct ArcLengthStep {
+    /// Load factor at the end of the step
+    pub lambda: f64,
+
+    /// Path parameter (accumulated arc-length) at the end of the step
+    pub path: f64,
+
+    /// Arc-length (Δl) used in the step (smaller than the nominal one if the step has been cut)
+    pub arc_length: f64,
+
+    /// Degrees of freedom at the end of the step (strain of the material point or displacements of the bar)
+    pub uu: Vec<f64>,
+
+    /// Indicates that a limit point has been passed in the step (the load factor increment has changed sign)
+    pub limit_point: bool,
+
+    /// Number of Newton iterations
+    pub n_iterations: usize,
+
+    /// Max norm of the residual at each iteration
+    pub residuals: Vec<f64>,
+}
+
+/// Implements the arc-length method (Riks/Crisfield) to follow equilibrium paths beyond limit points
+///
+/// The external load is `λ q` where `q` is a reference load and `λ` is the load factor. The load factor
+/// becomes an unknown and the increments are constrained by (Crisfield's spherical/cylindrical constraint):
+///
+/// ```text
+/// R(Δu, Δλ) = F_int(Δu) - (λ0 + Δλ) q = 0
+/// Δu·Δu + ψ² Δλ² q·q = Δl²
+/// ```
+///
+/// Each Newton iteration solves `K δu_R = -R` and `K δu_T = q`, with `K` assembled from the
+/// consistent tangent modulus, and computes `δλ` from the quadratic equation given by the constraint.
+/// The root closest to the previous increment direction is selected.
+#[derive(Clone, Copy, Debug)]
+pub struct ArcLength {
+    /// Arc-length Δl (nominal)
+    pub arc_length: f64,
+
+    /// Scaling factor of the load term in the constraint (ψ = 0 yields the cylindrical constraint)
+    pub psi: f64,
+
+    /// Tolerance on the max norm of the residual
+    pub tolerance: f64,
+
+    /// Maximum number of Newton iterations
+    pub n_iteration_max: usize,
+
+    /// Maximum number of times the arc-length is halved when a step fails
+    pub n_cut_max: usize,
+}
+
+/// Defines the equilibrium problem solved by the arc-length method
+pub(crate) trait Equilibrium {
+    /// Returns the reference load q
+    fn reference_load(&self) -> Vector;
+
+    /// Returns the stiffness K and the internal forces F_int for the increments Δu (from the last converged state)
+    fn evaluate(&self, ddu: &Vector) -> Result<(Matrix, Vector), Error>;
+
+    /// Accepts the increments Δu and returns the updated degrees of freedom
+    fn commit(&mut self, ddu: &Vector) -> Result<Vec<f64>, Error>;
+}
+
+/// Wraps a material point (y = λ y_ref) as an equilibrium problem
+struct MaterialPoint<'a, 'b> {
+    model: &'a Model<'b>,
+    x: f64,
+    y: f64,
+    y_ref: f64,
+}
+
+impl<'a, 'b> Equilibrium for MaterialPoint<'a, 'b> {
+    fn reference_load(&self) -> Vector {
+        Vector::from(&[self.y_ref])
+    }
+
+    fn evaluate(&self, ddu: &Vector) -> Result<(Matrix, Vector), Error> {
+        let (mut x1, mut y1) = (self.x, self.y);
+        let stats = self.model.backward_euler_update(&mut x1, &mut y1, ddu[0])?;
+        Ok((Matrix::from(&[[stats.ctm]]), Vector::from(&[y1])))
+    }
+
+    fn commit(&mut self, ddu: &Vector) -> Result<Vec<f64>, Error> {
+        self.model.backward_euler_update(&mut self.x, &mut self.y, ddu[0])?;
+        Ok(vec![self.x])
+    }
+}
+
+impl<'a> Equilibrium for Bar<'a> {
+    fn reference_load(&self) -> Vector {
+        let mut qq = self.external_forces();
+        let mut kk = Matrix::new(self.n_node(), self.n_node());
+        self.constrain(&mut kk, &mut qq);
+        qq
+    }
+
+    fn evaluate(&self, ddu: &Vector) -> Result<(Matrix, Vector), Error> {
+        let (mut kk, mut ff_int, _) = self.assemble(ddu)?;
+        self.constrain(&mut kk, &mut ff_int);
+        Ok((kk, ff_int))
+    }
+
+    fn commit(&mut self, ddu: &Vector) -> Result<Vec<f64>, Error> {
+        let (_, _, trial) = self.assemble(ddu)?;
+        Bar::commit(self, ddu, trial);
+        Ok(self.displacements().to_vec())
+    }
+}
+
+impl ArcLength {
+    /// Allocates a new instance
+    pub fn new(arc_length: f64) -> Self {
+        ArcLength {
+            arc_length,
+            psi: 1.0,
+            tolerance: 1e-10,
+            n_iteration_max: 20,
+            n_cut_max: 5,
+        }
+    }
+
+    /// Follows the stress-strain path of a material point under the stress `λ y_ref`
+    ///
+    /// The initial load factor is `y_ini / y_ref`; the degree of freedom is the strain.
+    pub fn run_material_point(
+        &self,
+        model: &Model,
+        x_ini: f64,
+        y_ini: f64,
+        y_ref: f64,
+        n_steps: usize,
+    ) -> Result<Vec<ArcLengthStep>, Error> {
+        let mut problem = MaterialPoint {
+            model,
+            x: x_ini,
+            y: y_ini,
+            y_ref,
+        };
+        self.run(&mut problem, y_ini / y_ref, n_steps)
+    }
+
+    /// Follows the load-displacement path of a bar under `λ` times its point loads and body force
+    ///
+    /// The bar must be in equilibrium with `lambda_ini` times its loads; the prescribed displacements are kept fixed.
+    pub fn run_bar(&self, bar: &mut Bar, lambda_ini: f64, n_steps: usize) -> Result<Vec<ArcLengthStep>, Error> {
+        self.run(bar, lambda_ini, n_steps)
+    }
+
+    /// Runs the arc-length method
+    pub(crate) fn run<P: Equilibrium>(
+        &self,
+        problem: &mut P,
+        lambda_ini: f64,
+        n_steps: usize,
+    ) -> Result<Vec<ArcLengthStep>, Error> {
+        if self.arc_length.is_nan() || self.arc_length <= 0.0 {
+            return Err(Error::InvalidParameter {
+                name: "arc_length".to_string(),
+                value: self.arc_length,
+                reason: "must be positive".to_string(),
+            });
+        }
+        let qq = problem.reference_load();
+        let q_dot_q = vec_inner(&qq, &qq);
+        if q_dot_q == 0.0 || !q_dot_q.is_finite() {
+            return Err(Error::InvalidLoading(
+                "the reference load must be non-zero and finite".to_string(),
+            ));
+        }
+        let mut lambda = lambda_ini;
+        let mut path = 0.0;
+        let mut previous: Option<(Vector, f64)> = None;
+        let mut steps = Vec::new();
+        for _ in 0..n_steps {
+            let mut ddl = self.arc_length;
+            let mut n_cut = 0;
+            let (ddu, ddlambda, residuals) = loop {
+                match self.step(problem, &qq, q_dot_q, lambda, ddl, previous.as_ref()) {
+                    Ok(res) => break res,
+                    Err(err) => {
+                        if n_cut == self.n_cut_max {
+                            return Err(err);
+                        }
+                        ddl /= 2.0;
+                        n_cut += 1;
+                    }
+                }
+            };
+            let uu = problem.commit(&ddu)?;
+            lambda += ddlambda;
+            path += ddl;
+            let limit_point = match previous {
+                Some((_, ddlambda_prev)) => ddlambda * ddlambda_prev < 0.0,
+                None => false,
+            };
+            steps.push(ArcLengthStep {
+                lambda,
+                path,
+                arc_length: ddl,
+                uu,
+                limit_point,
+                n_iterations: residuals.len() - 1,
+                residuals,
+            });
+            previous = Some((ddu, ddlambda));
+        }
+        Ok(steps)
+    }
+
+    /// Performs one arc-length step and returns (Δu, Δλ, residuals)
+    fn step<P: Equilibrium>(
+        &self,
+        problem: &P,
+        qq: &Vector,
+        q_dot_q: f64,
+        lambda: f64,
+        ddl: f64,
+        previous: Option<&(Vector, f64)>,
+    ) -> Result<(Vector, f64, Vec<f64>), Error> {
+        let psi2 = self.psi * self.psi;
+
+        // predictor along the tangent, following the direction of the previous increment
+        let mut ddu = Vector::new(qq.dim());
+        let (mut kk, _) = problem.evaluate(&ddu)?;
+        let uu_t = solve(&mut kk, qq)?;
+        let mut ddlambda = ddl / f64::sqrt(vec_inner(&uu_t, &uu_t) + psi2 * q_dot_q);
+        if let Some((ddu_prev, ddlambda_prev)) = previous
+            && vec_inner(&uu_t, ddu_prev) + psi2 * ddlambda_prev * q_dot_q < 0.0
+        {
+            ddlambda = -ddlambda;
+        }
+        for i in 0..ddu.dim() {
+            ddu[i] = ddlambda * uu_t[i];
+        }
+
+        // corrector
+        let mut residuals = Vec::new();
+        for _ in 0..=self.n_iteration_max {
+            let (kk, ff_int) = problem.evaluate(&ddu)?;
+            let mut rr = Vector::new(qq.dim());
+            for i in 0..rr.dim() {
+                rr[i] = -(ff_int[i] - (lambda + ddlambda) * qq[i]);
+            }
+            let norm = vec_norm(&rr, Norm::Max);
+            residuals.push(norm);
+            if norm < self.tolerance {
+                return Ok((ddu, ddlambda, residuals));
+            }
+            let uu_r = solve(&mut kk.clone(), &rr)?;
+            let uu_t = solve(&mut kk.clone(), qq)?;
+
+            // solve a1 δλ² + a2 δλ + a3 = 0 with w = Δu + δu_R
+            let mut ww = ddu.clone();
+            for i in 0..ww.dim() {
+                ww[i] += uu_r[i];
+            }
+            let a1 = vec_inner(&uu_t, &uu_t) + psi2 * q_dot_q;
+            let a2 = 2.0 * vec_inner(&uu_t, &ww) + 2.0 * psi2 * ddlambda * q_dot_q;
+            let a3 = vec_inner(&ww, &ww) + psi2 * ddlambda * ddlambda * q_dot_q - ddl * ddl;
+            let discriminant = a2 * a2 - 4.0 * a1 * a3;
+            if discriminant.is_nan() || discriminant < 0.0 {
+                return Err(Error::GlobalNewtonFailure {
+                    residual: norm,
+                    history: residuals,
+                });
+            }
+
+            // select the root yielding the smallest angle with the current increment
+            let mut best: Option<(f64, Vector, f64)> = None;
+            for sign in [-1.0, 1.0] {
+                let dlambda = (-a2 + sign * f64::sqrt(discriminant)) / (2.0 * a1);
+                let mut candidate = ww.clone();
+                for i in 0..candidate.dim() {
+                    candidate[i] += dlambda * uu_t[i];
+                }
+                let cosine = vec_inner(&candidate, &ddu) + psi2 * (ddlambda + dlambda) * ddlambda * q_dot_q;
+                if best.as_ref().is_none_or(|b| cosine > b.0) {
+                    best = Some((cosine, candidate, ddlambda + dlambda));
+                }
+            }
+            let (_, ddu_new, ddlambda_new) = best.unwrap();
+            ddu = ddu_new;
+            ddlambda = ddlambda_new;
+        }
+        Err(Error::GlobalNewtonFailure {
+            residual: *residuals.last().unwrap(),
+            history: residuals,
+        })
+    }
+}
+
+/// Solves K u = b
+fn solve(kk: &mut Matrix, b: &Vector) -> Result<Vector, Error> {
+    let mut u = b.clone();
+    solve_lin_sys(&mut u, kk).map_err(|e| Error::SingularJacobian(e.to_string()))?;
+    Ok(u)
+}
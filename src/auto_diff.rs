@@ -0,0 +1,58 @@
+use crate::{Dual, ModelTrait, Scalar};
+use russell_lab::Complex64;
+
+/// Defines a stress-strain model whose modulus f(x, y) is written once for any scalar type
+///
+/// Wrap the model in [AutoDiff] to obtain a [ModelTrait] with the derivatives L = ∂f/∂x and
+/// J = ∂f/∂y computed by forward-mode automatic differentiation (see [Dual]). Branches must be
+/// taken on the real part (see [Scalar::re]); the derivative is then the one of the active branch.
+pub trait GenericModel {
+    /// Calculates dy/dx = f(x, y)
+    fn eval_f<T: Scalar>(&self, x: T, y: T) -> T;
+}
+
+/// Implements [ModelTrait] for a [GenericModel] with automatic differentiation
+///
+/// ```text
+/// L = ∂f/∂x = dual part of f(x + ε, y)
+/// J = ∂f/∂y = dual part of f(x, y + ε)
+/// ```
+///
+/// The complex evaluation [ModelTrait::calc_f_complex] is also provided.
+pub struct AutoDiff<M: GenericModel> {
+    model: M,
+}
+
+impl<M: GenericModel> AutoDiff<M> {
+    /// Allocates a new instance
+    pub fn new(model: M) -> Self {
+        AutoDiff { model }
+    }
+
+    /// Returns the wrapped model
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+}
+
+impl<M: GenericModel> ModelTrait for AutoDiff<M> {
+    fn calc_f(&self, x: f64, y: f64) -> f64 {
+        self.model.eval_f(x, y)
+    }
+
+    fn calc_ll(&self, x: f64, y: f64) -> f64 {
+        self.model.eval_f(Dual::variable(x), Dual::constant(y)).deriv
+    }
+
+    fn calc_jj(&self, x: f64, y: f64) -> f64 {
+        self.model.eval_f(Dual::constant(x), Dual::variable(y)).deriv
+    }
+
+    fn approximate_derivatives(&self) -> bool {
+        false
+    }
+
+    fn calc_f_complex(&self, x: Complex64, y: Complex64) -> Option<Complex64> {
+        Some(self.model.eval_f(x, y))
+    }
+}
@@ -0,0 +1,316 @@
+use crate::{Error, Model};
+use russell_lab::{Matrix, Norm, Vector, solve_lin_sys, vec_norm};
+use std::collections::BTreeMap;
+
+/// Holds the state of an integration (Gauss) point
+#[derive(Clone, Copy, Debug)]
+pub struct GaussPoint {
+    /// Natural coordinate in [-1, 1]
+    pub xi: f64,
+
+    /// Integration weight
+    pub weight: f64,
+
+    /// Strain (x)
+    pub x: f64,
+
+    /// Stress (y)
+    pub y: f64,
+
+    /// Consistent tangent modulus of the last update
+    pub ctm: f64,
+}
+
+/// Holds the results of one loading step of the bar
+#[derive(Clone, Debug)]
+pub struct BarStep {
+    /// Number of global Newton iterations
+    pub n_iterations: usize,
+
+    /// Max norm of the residual (out-of-balance) force at each iteration
+    pub residuals: Vec<f64>,
+
+    /// Reaction forces at the nodes with prescribed displacements (node, reaction)
+    pub reactions: Vec<(usize, f64)>,
+}
+
+/// Implements a 1D bar discretized with linear (2-node) finite elements
+///
+/// Each element has one or more Gauss points holding their own strain-stress state, which are
+/// updated with [Model::backward_euler_update]. The global stiffness is assembled from the consistent
+/// tangent modulus of these updates ([crate::BackwardEulerStats::ctm], chained across substeps) and
+/// the equilibrium of each loading step is found with Newton's method:
+///
+/// ```text
+/// R(u) = F_int(u) - F_ext = 0
+/// K δu = -R  with  K = Σ Bᵀ D B A detJ w  and  D = dy/dx (consistent tangent modulus)
+/// ```
+pub struct Bar<'a> {
+    /// Material model
+    model: Model<'a>,
+
+    /// Cross-sectional area
+    area: f64,
+
+    /// Coordinates of the nodes
+    coords: Vec<f64>,
+
+    /// Gauss points of each element
+    gauss: Vec<Vec<GaussPoint>>,
+
+    /// Prescribed (total) displacements
+    prescribed: BTreeMap<usize, f64>,
+
+    /// Point loads
+    point_loads: Vector,
+
+    /// Body force per unit length
+    body_force: f64,
+
+    /// Displacements of the nodes
+    uu: Vector,
+
+    /// Tolerance on the max norm of the residual
+    pub tolerance: f64,
+
+    /// Maximum number of global Newton iterations
+    pub n_iteration_max: usize,
+}
+
+impl<'a> Bar<'a> {
+    /// Allocates a new instance
+    ///
+    /// # Input
+    ///
+    /// * `model` -- the material model
+    /// * `coords` -- the (increasing) coordinates of the nodes
+    /// * `area` -- the cross-sectional area
+    /// * `n_gauss` -- the number of Gauss points per element (1, 2, or 3)
+    pub fn new(model: Model<'a>, coords: &[f64], area: f64, n_gauss: usize) -> Result<Self, Error> {
+        if coords.len() < 2 || coords.iter().any(|c| !c.is_finite()) || coords.windows(2).any(|c| c[1] <= c[0]) {
+            return Err(Error::InvalidParameter {
+                name: "coords".to_string(),
+                value: coords.len() as f64,
+                reason: "there must be at least two nodes with increasing coordinates".to_string(),
+            });
+        }
+        if area.is_nan() || area <= 0.0 {
+            return Err(Error::InvalidParameter {
+                name: "area".to_string(),
+                value: area,
+                reason: "must be positive".to_string(),
+            });
+        }
+        let points: &[(f64, f64)] = match n_gauss {
+            1 => &[(0.0, 2.0)],
+            2 => &[(-1.0 / f64::sqrt(3.0), 1.0), (1.0 / f64::sqrt(3.0), 1.0)],
+            3 => &[
+                (-f64::sqrt(0.6), 5.0 / 9.0),
+                (0.0, 8.0 / 9.0),
+                (f64::sqrt(0.6), 5.0 / 9.0),
+            ],
+            _ => {
+                return Err(Error::InvalidParameter {
+                    name: "n_gauss".to_string(),
+                    value: n_gauss as f64,
+                    reason: "must be 1, 2, or 3".to_string(),
+                });
+            }
+        };
+        let n_element = coords.len() - 1;
+        let gauss = (0..n_element)
+            .map(|_| {
+                points
+                    .iter()
+                    .map(|&(xi, weight)| GaussPoint {
+                        xi,
+                        weight,
+                        x: 0.0,
+                        y: 0.0,
+                        ctm: 0.0,
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(Bar {
+            model,
+            area,
+            coords: coords.to_vec(),
+            gauss,
+            prescribed: BTreeMap::new(),
+            point_loads: Vector::new(coords.len()),
+            body_force: 0.0,
+            uu: Vector::new(coords.len()),
+            tolerance: 1e-10,
+            n_iteration_max: 20,
+        })
+    }
+
+    /// Allocates a new instance with equally spaced nodes in [0, length]
+    pub fn uniform(model: Model<'a>, length: f64, n_element: usize, area: f64, n_gauss: usize) -> Result<Self, Error> {
+        let coords: Vec<_> = (0..=n_element)
+            .map(|i| length * (i as f64) / (n_element as f64))
+            .collect();
+        Bar::new(model, &coords, area, n_gauss)
+    }
+
+    /// Returns the number of nodes
+    pub fn n_node(&self) -> usize {
+        self.coords.len()
+    }
+
+    /// Returns the material model
+    pub fn model(&self) -> &Model<'a> {
+        &self.model
+    }
+
+    /// Returns the displacements of the nodes
+    pub fn displacements(&self) -> &[f64] {
+        self.uu.as_data()
+    }
+
+    /// Returns the Gauss points of each element
+    pub fn gauss_points(&self) -> &[Vec<GaussPoint>] {
+        &self.gauss
+    }
+
+    /// Fixes the displacement of a node (support)
+    pub fn set_support(&mut self, node: usize) -> Result<&mut Self, Error> {
+        self.set_displacement(node, 0.0)
+    }
+
+    /// Prescribes the (total) displacement of a node
+    ///
+    /// The displacement is reached at the end of the next call to [Bar::solve_step].
+    pub fn set_displacement(&mut self, node: usize, value: f64) -> Result<&mut Self, Error> {
+        self.check_node(node)?;
+        self.prescribed.insert(node, value);
+        Ok(self)
+    }
+
+    /// Sets the (total) point load at a node
+    pub fn set_point_load(&mut self, node: usize, value: f64) -> Result<&mut Self, Error> {
+        self.check_node(node)?;
+        self.point_loads[node] = value;
+        Ok(self)
+    }
+
+    /// Sets the (total) body force per unit length
+    pub fn set_body_force(&mut self, value: f64) -> &mut Self {
+        self.body_force = value;
+        self
+    }
+
+    /// Solves the equilibrium for the current prescribed displacements and loads
+    ///
+    /// The increments are measured from the last converged state.
+    pub fn solve_step(&mut self) -> Result<BarStep, Error> {
+        let ff_ext = self.external_forces();
+        let mut dduu = Vector::new(self.n_node());
+        for (&node, &value) in &self.prescribed {
+            dduu[node] = value - self.uu[node];
+        }
+        let mut residuals = Vec::new();
+        for _ in 0..=self.n_iteration_max {
+            let (mut kk, ff_int, trial) = self.assemble(&dduu)?;
+            let mut rr = Vector::new(self.n_node());
+            for i in 0..self.n_node() {
+                rr[i] = ff_int[i] - ff_ext[i];
+            }
+            let mut rr_free = rr.clone();
+            self.constrain(&mut kk, &mut rr_free);
+            let norm = vec_norm(&rr_free, Norm::Max);
+            residuals.push(norm);
+            if norm < self.tolerance {
+                self.commit(&dduu, trial);
+                let reactions = self.prescribed.keys().map(|&node| (node, rr[node])).collect();
+                return Ok(BarStep {
+                    n_iterations: residuals.len() - 1,
+                    residuals,
+                    reactions,
+                });
+            }
+            let mut ddu = rr_free;
+            ddu.scale(-1.0);
+            solve_lin_sys(&mut ddu, &mut kk).map_err(|e| Error::SingularJacobian(e.to_string()))?;
+            for i in 0..self.n_node() {
+                dduu[i] += ddu[i];
+            }
+        }
+        Err(Error::GlobalNewtonFailure {
+            residual: *residuals.last().unwrap(),
+            history: residuals,
+        })
+    }
+
+    /// Calculates the external force vector (point loads and consistent body forces)
+    pub(crate) fn external_forces(&self) -> Vector {
+        let mut ff_ext = self.point_loads.clone();
+        for e in 0..self.gauss.len() {
+            let ll = self.coords[e + 1] - self.coords[e];
+            ff_ext[e] += self.body_force * ll / 2.0;
+            ff_ext[e + 1] += self.body_force * ll / 2.0;
+        }
+        ff_ext
+    }
+
+    /// Assembles the global stiffness and the internal forces for the displacement increments `dduu`
+    ///
+    /// Returns `(K, F_int, trial_gauss_points)`.
+    pub(crate) fn assemble(&self, dduu: &Vector) -> Result<(Matrix, Vector, Vec<Vec<GaussPoint>>), Error> {
+        let n = self.n_node();
+        let mut kk = Matrix::new(n, n);
+        let mut ff_int = Vector::new(n);
+        let mut trial = self.gauss.clone();
+        for (e, points) in trial.iter_mut().enumerate() {
+            let ll = self.coords[e + 1] - self.coords[e];
+            let bb = [-1.0 / ll, 1.0 / ll];
+            let det_jac = ll / 2.0;
+            let ddx = bb[0] * dduu[e] + bb[1] * dduu[e + 1];
+            for p in points.iter_mut() {
+                let stats = self.model.backward_euler_update(&mut p.x, &mut p.y, ddx)?;
+                p.ctm = stats.ctm;
+                let c = self.area * det_jac * p.weight;
+                for a in 0..2 {
+                    ff_int[e + a] += bb[a] * p.y * c;
+                    for b in 0..2 {
+                        kk.add(e + a, e + b, bb[a] * p.ctm * bb[b] * c);
+                    }
+                }
+            }
+        }
+        Ok((kk, ff_int, trial))
+    }
+
+    /// Imposes zero increments at the prescribed dofs by replacing their equations with `1 δu = 0`
+    pub(crate) fn constrain(&self, kk: &mut Matrix, rr: &mut Vector) {
+        for &node in self.prescribed.keys() {
+            for j in 0..self.n_node() {
+                kk.set(node, j, 0.0);
+                kk.set(j, node, 0.0);
+            }
+            kk.set(node, node, 1.0);
+            rr[node] = 0.0;
+        }
+    }
+
+    /// Accepts the displacement increments and the corresponding Gauss point states
+    pub(crate) fn commit(&mut self, dduu: &Vector, trial: Vec<Vec<GaussPoint>>) {
+        self.gauss = trial;
+        for i in 0..self.n_node() {
+            self.uu[i] += dduu[i];
+        }
+    }
+
+    /// Returns an error if the node index is out of range
+    fn check_node(&self, node: usize) -> Result<(), Error> {
+        if node >= self.n_node() {
+            return Err(Error::InvalidParameter {
+                name: "node".to_string(),
+                value: node as f64,
+                reason: format!("must be smaller than the number of nodes ({})", self.n_node()),
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{Error, LocalIntegrator, LocalParams, ModelTrait, newton_scalar};
+
+/// Implements the variable-increment, second-order backward differentiation formula (BDF2)
+///
+/// With h = Δx and ω = h / h_prev, the update reads:
+///
+/// ```text
+/// y1 - α1 y0 + α2 y_prev = h β f(x1, y1)
+///
+/// α1 = (1 + ω)² / (1 + 2ω)    α2 = ω² / (1 + 2ω)    β = (1 + ω) / (1 + 2ω)
+/// ```
+///
+/// where (y_prev, h_prev) is the history, i.e., the state before the last converged increment.
+/// The history is kept fixed when differentiating with respect to h; thus, ω depends on h and
+/// the consistent tangent modulus is (dω/dh = 1 / h_prev):
+///
+/// ```text
+/// dy1/dx1 = [(β + ω β') f1 + h β L1 + α1' (y0 - y_prev) / h_prev] / (1 - h β J1)
+///
+/// α1' = α2' = 2ω (1 + ω) / (1 + 2ω)²    β' = -1 / (1 + 2ω)²
+/// ```
+///
+/// The first increment (no history) uses backward Euler, which is recovered with ω = 0. The history
+/// is also discarded when the increment changes sign (e.g., upon unloading) because the formula
+/// becomes singular at ω = -1/2. Zero increments keep the history unchanged.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Bdf2 {
+    history: Option<(f64, f64)>,
+    local_params: LocalParams,
+}
+
+impl Bdf2 {
+    /// Allocates a new instance
+    pub fn new() -> Self {
+        Bdf2 {
+            history: None,
+            local_params: LocalParams::new(),
+        }
+    }
+
+    /// Returns the history (y_prev, h_prev), if any
+    pub fn history(&self) -> Option<(f64, f64)> {
+        self.history
+    }
+
+    /// Returns the parameters of the local solver
+    pub fn local_params(&self) -> &LocalParams {
+        &self.local_params
+    }
+}
+
+impl LocalIntegrator for Bdf2 {
+    fn name(&self) -> String {
+        "BDF2".to_string()
+    }
+
+    fn update(&mut self, model: &dyn ModelTrait, x: &mut f64, y: &mut f64, ddx: f64) -> Result<f64, Error> {
+        let (x0, y0, h) = (*x, *y, ddx);
+        let x1 = x0 + h;
+
+        // ω = 0 yields backward Euler
+        let (y_prev, h_prev, omega) = match self.history {
+            Some((y_prev, h_prev)) if h * h_prev > 0.0 => (y_prev, h_prev, h / h_prev),
+            _ => (y0, 1.0, 0.0),
+        };
+        let den = 1.0 + 2.0 * omega;
+        let alpha1 = (1.0 + omega) * (1.0 + omega) / den;
+        let alpha2 = omega * omega / den;
+        let beta = (1.0 + omega) / den;
+
+        // r(y1) = y1 - α1 y0 + α2 y_prev - h β f(x1, y1)
+        let y_trial = y0 + h * model.calc_f(x0, y0);
+        let (y1, _) = newton_scalar(&self.local_params, x0, y0, ddx, y_trial, |y1| {
+            let f1 = model.calc_f(x1, y1);
+            let jj1 = model.calc_jj(x1, y1);
+            (y1 - alpha1 * y0 + alpha2 * y_prev - h * beta * f1, 1.0 - h * beta * jj1)
+        })?;
+
+        // consistent tangent modulus
+        let f1 = model.calc_f(x1, y1);
+        let ll1 = model.calc_ll(x1, y1);
+        let jj1 = model.calc_jj(x1, y1);
+        let d_alpha1 = 2.0 * omega * (1.0 + omega) / (den * den);
+        let d_beta = -1.0 / (den * den);
+        let ctm = ((beta + omega * d_beta) * f1 + h * beta * ll1 + d_alpha1 * (y0 - y_prev) / h_prev)
+            / (1.0 - h * beta * jj1);
+
+        // update the history
+        if h != 0.0 {
+            self.history = Some((y0, h));
+        }
+        *x = x1;
+        *y = y1;
+        Ok(ctm)
+    }
+
+    fn reset(&mut self) {
+        self.history = None;
+    }
+
+    fn set_local_params(&mut self, params: &LocalParams) {
+        self.local_params = *params;
+    }
+}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dahlquist, DahlquistParams};
+    use russell_lab::approx_eq;
+
+    #[test]
+    fn update_handles_the_history() {
+        // Dahlquist: the first step is backward Euler
+        let lambda = 5.0;
+        let model = Dahlquist::new(DahlquistParams::new().with_lambda(lambda)).unwrap();
+        let mut bdf = Bdf2::new();
+        let (mut x, mut y) = (0.0, 1.0);
+        let ddx = 0.1;
+        bdf.update(&model, &mut x, &mut y, ddx).unwrap();
+        let y_be = 1.0 / (1.0 + lambda * ddx);
+        approx_eq(y, y_be, 1e-15);
+        assert_eq!(bdf.history(), Some((1.0, ddx)));
+
+        // constant increments: y2 = (4/3 y1 - 1/3 y0) / (1 + 2/3 λ Δx)
+        bdf.update(&model, &mut x, &mut y, ddx).unwrap();
+        approx_eq(y, (4.0 * y_be - 1.0) / 3.0 / (1.0 + 2.0 * lambda * ddx / 3.0), 1e-15);
+        assert_eq!(bdf.history(), Some((y_be, ddx)));
+
+        // zero increments keep the state and the history
+        let (x2, y2) = (x, y);
+        let ctm = bdf.update(&model, &mut x, &mut y, 0.0).unwrap();
+        assert_eq!((x, y), (x2, y2));
+        approx_eq(ctm, -lambda * y2, 1e-15);
+        assert_eq!(bdf.history(), Some((y_be, ddx)));
+
+        // reversal: backward Euler
+        bdf.update(&model, &mut x, &mut y, -ddx).unwrap();
+        approx_eq(y, y2 / (1.0 - lambda * ddx), 1e-15);
+        assert_eq!(bdf.history(), Some((y2, -ddx)));
+
+        // reset
+        bdf.reset();
+        assert_eq!(bdf.history(), None);
+    }
+}
@@ -0,0 +1,334 @@
+use crate::{DifferenceScheme, Error, Model, Sampling};
+use russell_lab::deriv1_central5;
+
+/// Holds a sample of the conformance check with the analytical and numerical values
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConformancePoint {
+    /// Checked quantity ("f", "L", "J" or "CTM")
+    pub quantity: &'static str,
+
+    /// Strain (at the beginning of the increment for the CTM)
+    pub x: f64,
+
+    /// Stress (at the beginning of the increment for the CTM)
+    pub y: f64,
+
+    /// Strain increment (zero for f, L and J)
+    pub ddx: f64,
+
+    /// Value given by the model (or the CTM of [Model::backward_euler_update])
+    pub analytical: f64,
+
+    /// Value given by finite differences
+    pub numerical: f64,
+
+    /// Relative error `|analytical - numerical| / max(|numerical|, 1)`
+    pub error: f64,
+}
+
+/// Holds the results of the conformance check (see [Conformance::check])
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConformanceReport {
+    /// Number of sampled points
+    pub n_points: usize,
+
+    /// Number of CTM comparisons
+    pub n_ctm: usize,
+
+    /// Worst points of L = ∂f/∂x (sorted by decreasing error)
+    pub worst_ll: Vec<ConformancePoint>,
+
+    /// Worst points of J = ∂f/∂y (sorted by decreasing error)
+    pub worst_jj: Vec<ConformancePoint>,
+
+    /// Worst points of the consistent tangent modulus (sorted by decreasing error)
+    pub worst_ctm: Vec<ConformancePoint>,
+
+    /// Points where the model (or the numerical derivatives) produced NaN or Inf
+    pub non_finite: Vec<ConformancePoint>,
+
+    /// Backward Euler updates that failed, with the corresponding error
+    pub unsolved: Vec<(f64, f64, f64, Error)>,
+
+    /// Tolerance on the relative error of L and J
+    pub tol_derivatives: f64,
+
+    /// Tolerance on the relative error of the consistent tangent modulus
+    pub tol_ctm: f64,
+}
+
+impl ConformanceReport {
+    /// Returns the largest relative error of a list of worst points (zero if empty)
+    fn max_error(points: &[ConformancePoint]) -> f64 {
+        points.first().map_or(0.0, |p| p.error)
+    }
+
+    /// Returns the largest relative error of L
+    pub fn max_error_ll(&self) -> f64 {
+        Self::max_error(&self.worst_ll)
+    }
+
+    /// Returns the largest relative error of J
+    pub fn max_error_jj(&self) -> f64 {
+        Self::max_error(&self.worst_jj)
+    }
+
+    /// Returns the largest relative error of the consistent tangent modulus
+    pub fn max_error_ctm(&self) -> f64 {
+        Self::max_error(&self.worst_ctm)
+    }
+
+    /// Returns true if all values are finite, all backward Euler updates succeeded and all errors are
+    /// within the tolerances
+    ///
+    /// A check without any CTM comparison (e.g., with an empty `ddx_list`) only verifies L and J.
+    pub fn passed(&self) -> bool {
+        self.non_finite.is_empty()
+            && self.unsolved.is_empty()
+            && self.max_error_ll() <= self.tol_derivatives
+            && self.max_error_jj() <= self.tol_derivatives
+            && self.max_error_ctm() <= self.tol_ctm
+    }
+}
+
+/// Implements a derivative conformance check for any [crate::ModelTrait] implementation
+///
+/// At each sampled point (x, y) of the domain, the check:
+///
+/// 1. compares L = ∂f/∂x and J = ∂f/∂y with [russell_lab::deriv1_central5];
+/// 2. performs [Model::backward_euler_update] from (x, y) with each `Δx` of `ddx_list` and compares
+///    the returned consistent tangent modulus ([crate::BackwardEulerStats::ctm]) with the 4th-order
+///    central difference of the update (see [DifferenceScheme::Central4]);
+/// 3. records the values that are NaN or Inf.
+///
+/// The updates follow the configuration of the model (local solver and substepping). The CTM is
+/// evaluated at the approximate root and the finite differences are affected by the tolerance of the
+/// local solver; thus, tight tolerances are required (e.g., `abs_tol = 1e-13` for `tol_ctm = 1e-6`).
+///
+/// Derivatives are not meaningful at kinks (e.g., `max(0, yr - y)`); thus, the worst points are
+/// expected there.
+#[derive(Clone, Debug)]
+pub struct Conformance {
+    /// Strain range (min, max)
+    pub x_range: (f64, f64),
+
+    /// Stress range (min, max)
+    pub y_range: (f64, f64),
+
+    /// Sampling of the domain
+    pub sampling: Sampling,
+
+    /// Strain increments of the CTM comparisons
+    pub ddx_list: Vec<f64>,
+
+    /// Tolerance on the relative error of L and J
+    pub tol_derivatives: f64,
+
+    /// Tolerance on the relative error of the consistent tangent modulus
+    pub tol_ctm: f64,
+
+    /// Number of worst points kept in the report (for each quantity)
+    pub n_worst: usize,
+}
+
+impl Conformance {
+    /// Allocates a new instance with an 11 × 11 grid on the given domain
+    pub fn new(x_range: (f64, f64), y_range: (f64, f64)) -> Self {
+        Conformance {
+            x_range,
+            y_range,
+            sampling: Sampling::Grid { nx: 11, ny: 11 },
+            ddx_list: vec![0.001, 0.01, 0.1],
+            tol_derivatives: 1e-6,
+            tol_ctm: 1e-6,
+            n_worst: 5,
+        }
+    }
+
+    /// Returns the sampled points
+    pub fn points(&self) -> Vec<(f64, f64)> {
+        let (x_min, x_max) = self.x_range;
+        let (y_min, y_max) = self.y_range;
+        let lerp = |a: f64, b: f64, t: f64| a + (b - a) * t;
+        match self.sampling {
+            Sampling::Grid { nx, ny } => {
+                let frac = |i: usize, n: usize| if n > 1 { i as f64 / (n - 1) as f64 } else { 0.0 };
+                let mut points = Vec::with_capacity(nx * ny);
+                for i in 0..nx {
+                    for j in 0..ny {
+                        points.push((lerp(x_min, x_max, frac(i, nx)), lerp(y_min, y_max, frac(j, ny))));
+                    }
+                }
+                points
+            }
+            Sampling::Random { n, seed } => {
+                let mut state = seed;
+                (0..n)
+                    .map(|_| {
+                        let u = uniform(&mut state);
+                        let v = uniform(&mut state);
+                        (lerp(x_min, x_max, u), lerp(y_min, y_max, v))
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Checks the model and returns the report
+    pub fn check(&self, model: &Model) -> ConformanceReport {
+        let mut report = ConformanceReport {
+            n_points: 0,
+            n_ctm: 0,
+            worst_ll: Vec::new(),
+            worst_jj: Vec::new(),
+            worst_ctm: Vec::new(),
+            non_finite: Vec::new(),
+            unsolved: Vec::new(),
+            tol_derivatives: self.tol_derivatives,
+            tol_ctm: self.tol_ctm,
+        };
+        let actual = model.actual();
+        let local_params = model.local_params();
+        let scheme = DifferenceScheme::Central4;
+        for (x, y) in self.points() {
+            report.n_points += 1;
+            let f = actual.calc_f(x, y);
+            if !f.is_finite() {
+                report.non_finite.push(point("f", x, y, 0.0, f, f64::NAN));
+                continue;
+            }
+
+            // derivatives
+            let ll = actual.calc_ll(x, y);
+            let jj = actual.calc_jj(x, y);
+            let num_ll = deriv1_central5(x, &mut 0, |x, _| Ok(actual.calc_f(x, y))).unwrap_or(f64::NAN);
+            let num_jj = deriv1_central5(y, &mut 0, |y, _| Ok(actual.calc_f(x, y))).unwrap_or(f64::NAN);
+            self.record(
+                &mut report.worst_ll,
+                &mut report.non_finite,
+                point("L", x, y, 0.0, ll, num_ll),
+            );
+            self.record(
+                &mut report.worst_jj,
+                &mut report.non_finite,
+                point("J", x, y, 0.0, jj, num_jj),
+            );
+
+            // consistent tangent modulus
+            for &ddx in &self.ddx_list {
+                let h = scheme.auto_step(ddx, local_params.abs_tol + local_params.rel_tol * f64::abs(y));
+                match tangents(model, x, y, ddx, scheme, h) {
+                    Ok((ctm, num)) => {
+                        report.n_ctm += 1;
+                        self.record(
+                            &mut report.worst_ctm,
+                            &mut report.non_finite,
+                            point("CTM", x, y, ddx, ctm, num),
+                        );
+                    }
+                    Err(err) => report.unsolved.push((x, y, ddx, err)),
+                }
+            }
+        }
+        report
+    }
+
+    /// Records a comparison in the list of worst points (or of non-finite points)
+    fn record(&self, worst: &mut Vec<ConformancePoint>, non_finite: &mut Vec<ConformancePoint>, p: ConformancePoint) {
+        if !p.error.is_finite() {
+            non_finite.push(p);
+            return;
+        }
+        let index = worst.partition_point(|q| q.error >= p.error);
+        if index < self.n_worst {
+            worst.insert(index, p);
+            worst.truncate(self.n_worst);
+        }
+    }
+}
+
+/// Returns the consistent tangent modulus of the backward Euler update from (x, y) and its numerical approximation
+fn tangents(model: &Model, x: f64, y: f64, ddx: f64, scheme: DifferenceScheme, h: f64) -> Result<(f64, f64), Error> {
+    let (mut x1, mut y1) = (x, y);
+    let ctm = model.backward_euler_update(&mut x1, &mut y1, ddx)?.ctm;
+    let num = scheme.differentiate(h, |d| {
+        let (mut x1, mut y1) = (x, y);
+        model.backward_euler_update(&mut x1, &mut y1, ddx + d)?;
+        Ok(y1)
+    })?;
+    Ok((ctm, num.value))
+}
+
+/// Allocates a conformance point and calculates the relative error
+fn point(quantity: &'static str, x: f64, y: f64, ddx: f64, analytical: f64, numerical: f64) -> ConformancePoint {
+    let error = f64::abs(analytical - numerical) / f64::max(f64::abs(numerical), 1.0);
+    ConformancePoint {
+        quantity,
+        x,
+        y,
+        ddx,
+        analytical,
+        numerical,
+        error,
+    }
+}
+
+/// Returns a pseudo-random number in [0, 1) and advances the state (SplitMix64)
+fn uniform(state: &mut u64) -> f64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 11) as f64 / (1u64 << 53) as f64
+}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_work() {
+        let mut kit = Conformance::new((0.0, 1.0), (-1.0, 1.0));
+        kit.sampling = Sampling::Grid { nx: 3, ny: 2 };
+        assert_eq!(
+            kit.points(),
+            &[
+                (0.0, -1.0),
+                (0.0, 1.0),
+                (0.5, -1.0),
+                (0.5, 1.0),
+                (1.0, -1.0),
+                (1.0, 1.0)
+            ]
+        );
+        kit.sampling = Sampling::Random { n: 100, seed: 7 };
+        let points = kit.points();
+        assert_eq!(points.len(), 100);
+        assert!(
+            points
+                .iter()
+                .all(|(x, y)| (0.0..1.0).contains(x) && (-1.0..1.0).contains(y))
+        );
+        assert_eq!(points, kit.points());
+    }
+
+    #[test]
+    fn record_keeps_the_worst_points() {
+        let mut kit = Conformance::new((0.0, 1.0), (0.0, 1.0));
+        kit.n_worst = 2;
+        let (mut worst, mut non_finite) = (Vec::new(), Vec::new());
+        for (i, error) in [0.1, 0.3, 0.2, f64::NAN].iter().enumerate() {
+            kit.record(
+                &mut worst,
+                &mut non_finite,
+                point("L", i as f64, 0.0, 0.0, 1.0 + error, 1.0),
+            );
+        }
+        let x: Vec<_> = worst.iter().map(|p| p.x).collect();
+        assert_eq!(x, &[1.0, 2.0]);
+        assert_eq!(non_finite.len(), 1);
+    }
+}
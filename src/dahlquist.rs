@@ -1,6 +1,60 @@
-use crate::{ModelTrait, StrError};
+use crate::{Error, GenericModel, ModelTrait, ParamInfo, Scalar, check_param_keys, get_param};
+use russell_lab::Complex64;
 use std::collections::HashMap;
 
+/// Holds the parameters of the Dahlquist model
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DahlquistParams {
+    /// Decay constant (λ) in units of 1/x; must be non-negative
+    pub lambda: f64,
+}
+
+impl DahlquistParams {
+    /// Holds the metadata of the parameters
+    pub const INFO: [ParamInfo; 1] = [ParamInfo {
+        name: "lambda",
+        description: "decay constant (λ)",
+        units: "1/x",
+        default: 1.0,
+        min: 0.0,
+        min_exclusive: false,
+        max: f64::INFINITY,
+    }];
+
+    /// Allocates a new instance with default values
+    pub fn new() -> Self {
+        DahlquistParams {
+            lambda: Self::INFO[0].default,
+        }
+    }
+
+    /// Sets the decay constant (λ)
+    pub fn with_lambda(mut self, lambda: f64) -> Self {
+        self.lambda = lambda;
+        self
+    }
+
+    /// Allocates a new instance from a map of named values
+    ///
+    /// Returns an error if a parameter is missing or if an unknown parameter is given.
+    pub fn from_map(params: &HashMap<&str, f64>) -> Result<Self, Error> {
+        check_param_keys("Dahlquist", params, &Self::INFO)?;
+        let lambda = get_param("Dahlquist", params, "lambda")?;
+        Ok(DahlquistParams { lambda })
+    }
+
+    /// Validates the parameters
+    pub fn validate(&self) -> Result<(), Error> {
+        Self::INFO[0].check(self.lambda)
+    }
+}
+
+impl Default for DahlquistParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Dahlquist model for testing purposes
 ///
 /// ```text
@@ -14,12 +68,10 @@ pub struct Dahlquist {
 impl Dahlquist {
     /// Allocates a new instance
     ///
-    /// # Parameters
-    ///
-    /// * `lambda` - decay constant (λ)
-    pub fn new(params: HashMap<&str, f64>) -> Result<Self, StrError> {
-        let lambda = *params.get("lambda").ok_or("Parameter 'lambda' not found")?;
-        Ok(Dahlquist { lambda })
+    /// Returns an error if the parameters are invalid (see [DahlquistParams::validate]).
+    pub fn new(params: DahlquistParams) -> Result<Self, Error> {
+        params.validate()?;
+        Ok(Dahlquist { lambda: params.lambda })
     }
 
     /// Calculates y(x)
@@ -33,10 +85,17 @@ impl Dahlquist {
     }
 }
 
+impl GenericModel for Dahlquist {
+    /// Calculates dy/dx = f(x,y) for any scalar type
+    fn eval_f<T: Scalar>(&self, _x: T, y: T) -> T {
+        -y * self.lambda
+    }
+}
+
 impl ModelTrait for Dahlquist {
     /// Calculates dy/dx = f(x,y)
-    fn calc_f(&self, _x: f64, y: f64) -> f64 {
-        -self.lambda * y
+    fn calc_f(&self, x: f64, y: f64) -> f64 {
+        self.eval_f(x, y)
     }
 
     /// Calculates L = ∂f/∂x
@@ -48,6 +107,16 @@ impl ModelTrait for Dahlquist {
     fn calc_jj(&self, _x: f64, _y: f64) -> f64 {
         -self.lambda
     }
+
+    /// Returns false because L and J are exact
+    fn approximate_derivatives(&self) -> bool {
+        false
+    }
+
+    /// Calculates f(x,y) with complex arguments
+    fn calc_f_complex(&self, x: Complex64, y: Complex64) -> Option<Complex64> {
+        Some(self.eval_f(x, y))
+    }
 }
 
 // tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -58,7 +127,31 @@ mod tests {
 
     #[test]
     fn new_works() {
-        let dahlquist = Dahlquist::new(HashMap::from([("lambda", 1.0)])).unwrap();
-        assert_eq!(dahlquist.lambda, 1.0);
+        let dahlquist = Dahlquist::new(DahlquistParams::new().with_lambda(2.0)).unwrap();
+        assert_eq!(dahlquist.lambda, 2.0);
+        assert_eq!(
+            Dahlquist::new(DahlquistParams::new().with_lambda(-1.0)).err(),
+            Some(Error::InvalidParameter {
+                name: "lambda".to_string(),
+                value: -1.0,
+                reason: "must be finite and non-negative".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn from_map_works() {
+        let params = DahlquistParams::from_map(&HashMap::from([("lambda", 3.0)])).unwrap();
+        assert_eq!(params.lambda, 3.0);
+        assert_eq!(
+            DahlquistParams::from_map(&HashMap::new()).unwrap_err().to_string(),
+            "parameter 'lambda' of Dahlquist not found"
+        );
+        assert_eq!(
+            DahlquistParams::from_map(&HashMap::from([("lambda", 3.0), ("lamda", 3.0)]))
+                .unwrap_err()
+                .to_string(),
+            "parameter 'lamda' is not recognized by Dahlquist"
+        );
     }
 }
@@ -0,0 +1,148 @@
+use crate::Scalar;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Implements a dual number `a + b ε` with `ε² = 0` for forward-mode automatic differentiation
+///
+/// Evaluating a function on `x + ε` carries the derivative along with the value:
+///
+/// ```text
+/// g(x + ε) = g(x) + g'(x) ε
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Dual {
+    /// Value (real part)
+    pub value: f64,
+
+    /// Derivative (dual part)
+    pub deriv: f64,
+}
+
+impl Dual {
+    /// Allocates a new instance
+    pub fn new(value: f64, deriv: f64) -> Self {
+        Dual { value, deriv }
+    }
+
+    /// Allocates the independent variable (with unit derivative)
+    pub fn variable(value: f64) -> Self {
+        Dual { value, deriv: 1.0 }
+    }
+
+    /// Allocates a constant (with zero derivative)
+    pub fn constant(value: f64) -> Self {
+        Dual { value, deriv: 0.0 }
+    }
+}
+
+impl Add for Dual {
+    type Output = Dual;
+    fn add(self, other: Dual) -> Dual {
+        Dual::new(self.value + other.value, self.deriv + other.deriv)
+    }
+}
+
+impl Sub for Dual {
+    type Output = Dual;
+    fn sub(self, other: Dual) -> Dual {
+        Dual::new(self.value - other.value, self.deriv - other.deriv)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Dual;
+    fn mul(self, other: Dual) -> Dual {
+        Dual::new(
+            self.value * other.value,
+            self.deriv * other.value + self.value * other.deriv,
+        )
+    }
+}
+
+impl Div for Dual {
+    type Output = Dual;
+    fn div(self, other: Dual) -> Dual {
+        Dual::new(
+            self.value / other.value,
+            (self.deriv * other.value - self.value * other.deriv) / (other.value * other.value),
+        )
+    }
+}
+
+impl Neg for Dual {
+    type Output = Dual;
+    fn neg(self) -> Dual {
+        Dual::new(-self.value, -self.deriv)
+    }
+}
+
+impl Add<f64> for Dual {
+    type Output = Dual;
+    fn add(self, other: f64) -> Dual {
+        Dual::new(self.value + other, self.deriv)
+    }
+}
+
+impl Sub<f64> for Dual {
+    type Output = Dual;
+    fn sub(self, other: f64) -> Dual {
+        Dual::new(self.value - other, self.deriv)
+    }
+}
+
+impl Mul<f64> for Dual {
+    type Output = Dual;
+    fn mul(self, other: f64) -> Dual {
+        Dual::new(self.value * other, self.deriv * other)
+    }
+}
+
+impl Div<f64> for Dual {
+    type Output = Dual;
+    fn div(self, other: f64) -> Dual {
+        Dual::new(self.value / other, self.deriv / other)
+    }
+}
+
+impl Scalar for Dual {
+    fn from_f64(value: f64) -> Self {
+        Dual::constant(value)
+    }
+
+    fn re(self) -> f64 {
+        self.value
+    }
+
+    fn exp(self) -> Self {
+        let e = f64::exp(self.value);
+        Dual::new(e, e * self.deriv)
+    }
+
+    fn ln(self) -> Self {
+        Dual::new(f64::ln(self.value), self.deriv / self.value)
+    }
+}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use russell_lab::approx_eq;
+
+    fn g<T: Scalar>(x: T) -> T {
+        (x * x * 2.0 + 1.0).ln() / (-x).exp() - x / (x + 3.0) * 0.5
+    }
+
+    #[test]
+    fn derivatives_work() {
+        // g'(x) = exp(x) [4x / (2x² + 1) + ln(2x² + 1)] - 1.5 / (x + 3)²
+        for x in [-1.5, 0.0, 0.7, 4.0] {
+            let res = g(Dual::variable(x));
+            assert_eq!(res.value, g(x));
+            let correct = f64::exp(x) * (4.0 * x / (2.0 * x * x + 1.0) + f64::ln(2.0 * x * x + 1.0))
+                - 1.5 / ((x + 3.0) * (x + 3.0));
+            approx_eq(res.deriv, correct, 1e-14);
+        }
+        assert_eq!(g(Dual::constant(0.7)).deriv, 0.0);
+    }
+}
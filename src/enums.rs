@@ -1,5 +1,64 @@
+use crate::{DahlquistParams, HardeningSofteningParams, ParamInfo};
+
 /// Defines the type of model used in the simulation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ModelType {
     Dahlquist,
     HardeningSoftening,
 }
+
+impl ModelType {
+    /// Returns the name of the model (as used by the registry)
+    pub fn name(&self) -> &'static str {
+        match self {
+            ModelType::Dahlquist => "Dahlquist",
+            ModelType::HardeningSoftening => "HardeningSoftening",
+        }
+    }
+
+    /// Returns the metadata of the parameters (names, descriptions, units, defaults and admissible ranges)
+    pub fn params_info(&self) -> &'static [ParamInfo] {
+        match self {
+            ModelType::Dahlquist => &DahlquistParams::INFO,
+            ModelType::HardeningSoftening => &HardeningSofteningParams::INFO,
+        }
+    }
+
+    /// Returns all built-in model types
+    pub fn all() -> [ModelType; 2] {
+        [ModelType::Dahlquist, ModelType::HardeningSoftening]
+    }
+}
+
+/// Defines how the points of a domain are sampled
+///
+/// See [crate::Conformance].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sampling {
+    /// Regular grid with nx × ny points (including the bounds)
+    Grid { nx: usize, ny: usize },
+
+    /// Pseudo-random points (reproducible given the seed)
+    Random { n: usize, seed: u64 },
+}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn params_info_works() {
+        let names: Vec<_> = ModelType::HardeningSoftening
+            .params_info()
+            .iter()
+            .map(|p| p.name)
+            .collect();
+        assert_eq!(names, &["li", "lr", "y0r", "a", "b"]);
+        let info = &ModelType::Dahlquist.params_info()[0];
+        assert_eq!(info.name, "lambda");
+        assert!(info.admits(info.default));
+        assert!(!info.admits(-1.0));
+    }
+}
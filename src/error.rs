@@ -0,0 +1,225 @@
+use std::fmt;
+
+/// Defines the errors returned by this crate
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// A required parameter has not been given
+    MissingParameter {
+        /// Name of the model
+        model: String,
+        /// Name of the parameter
+        name: String,
+    },
+
+    /// A parameter that the model does not recognize has been given (e.g., misspelled)
+    UnknownParameter {
+        /// Name of the model
+        model: String,
+        /// Name of the unrecognized parameter
+        name: String,
+    },
+
+    /// A parameter is outside its admissible range
+    InvalidParameter {
+        /// Name of the parameter
+        name: String,
+        /// Given value
+        value: f64,
+        /// Explanation of the admissible range
+        reason: String,
+    },
+
+    /// The model name is not found in the registry
+    UnknownModel(String),
+
+    /// The model name is already present in the registry
+    DuplicateModel(String),
+
+    /// The loading protocol is invalid
+    InvalidLoading(String),
+
+    /// The model does not support the requested operation (e.g., complex arithmetic)
+    Unsupported(String),
+
+    /// The local Newton iterations of the backward Euler update did not converge
+    LocalNewtonFailure {
+        /// Strain at the beginning of the increment
+        x0: f64,
+        /// Stress at the beginning of the increment
+        y0: f64,
+        /// Strain increment (Δx)
+        ddx: f64,
+        /// Last residual
+        residual: f64,
+        /// Absolute value of the residual at each iteration
+        history: Vec<f64>,
+    },
+
+    /// The substep size of a substepping update became smaller than the minimum
+    SubstepTooSmall {
+        /// Strain at the beginning of the increment
+        x0: f64,
+        /// Stress at the beginning of the increment
+        y0: f64,
+        /// Strain increment (Δx)
+        ddx: f64,
+        /// Pseudo-time (fraction of the increment) reached before the failure
+        pseudo_time: f64,
+    },
+
+    /// The number of substeps of a substepping update reached the maximum
+    TooManySubsteps {
+        /// Strain at the beginning of the increment
+        x0: f64,
+        /// Stress at the beginning of the increment
+        y0: f64,
+        /// Strain increment (Δx)
+        ddx: f64,
+        /// Pseudo-time (fraction of the increment) reached before the failure
+        pseudo_time: f64,
+        /// Maximum number of substeps (accepted and rejected)
+        n_substep_max: usize,
+    },
+
+    /// The global Newton iterations (e.g., of a stress-controlled step) did not converge
+    GlobalNewtonFailure {
+        /// Last residual
+        residual: f64,
+        /// Absolute value (or norm) of the residual at each iteration
+        history: Vec<f64>,
+    },
+
+    /// The Jacobian (stiffness) of the global Newton iterations is singular
+    SingularJacobian(String),
+
+    /// The ODE solver could not be allocated
+    OdeSolverSetup(russell_ode::StrError),
+
+    /// The ODE solver failed to integrate the increment
+    OdeSolverFailure {
+        /// Message returned by russell_ode
+        message: russell_ode::StrError,
+        /// Strain at the beginning of the increment
+        x0: f64,
+        /// Stress at the beginning of the increment
+        y0: f64,
+        /// Strain increment (Δx)
+        ddx: f64,
+    },
+
+    /// A NaN or infinite value has been computed
+    NonFinite {
+        /// Description of the quantity
+        what: &'static str,
+        /// Strain at which the quantity has been computed
+        x: f64,
+        /// Stress at which the quantity has been computed
+        y: f64,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingParameter { model, name } => write!(f, "parameter '{}' of {} not found", name, model),
+            Error::UnknownParameter { model, name } => write!(f, "parameter '{}' is not recognized by {}", name, model),
+            Error::InvalidParameter { name, value, reason } => {
+                write!(f, "parameter '{}' = {} is invalid: {}", name, value, reason)
+            }
+            Error::UnknownModel(name) => write!(f, "model '{}' not found in the registry", name),
+            Error::DuplicateModel(name) => write!(f, "model '{}' is already registered", name),
+            Error::InvalidLoading(message) => write!(f, "invalid loading protocol: {}", message),
+            Error::Unsupported(message) => write!(f, "unsupported operation: {}", message),
+            Error::LocalNewtonFailure {
+                x0,
+                y0,
+                ddx,
+                residual,
+                history,
+            } => write!(
+                f,
+                "backward Euler did not converge after {} iterations (x0 = {}, y0 = {}, Δx = {}, residual = {:e})",
+                history.len(),
+                x0,
+                y0,
+                ddx,
+                residual
+            ),
+            Error::SubstepTooSmall {
+                x0,
+                y0,
+                ddx,
+                pseudo_time,
+            } => write!(
+                f,
+                "the substep became too small at T = {} (x0 = {}, y0 = {}, Δx = {})",
+                pseudo_time, x0, y0, ddx
+            ),
+            Error::TooManySubsteps {
+                x0,
+                y0,
+                ddx,
+                pseudo_time,
+                n_substep_max,
+            } => write!(
+                f,
+                "the maximum number of substeps ({}) was reached at T = {} (x0 = {}, y0 = {}, Δx = {})",
+                n_substep_max, pseudo_time, x0, y0, ddx
+            ),
+            Error::GlobalNewtonFailure { residual, history } => write!(
+                f,
+                "global Newton did not converge after {} iterations (residual = {:e})",
+                history.len(),
+                residual
+            ),
+            Error::SingularJacobian(message) => write!(f, "singular Jacobian: {}", message),
+            Error::OdeSolverSetup(message) => write!(f, "cannot allocate the ODE solver: {}", message),
+            Error::OdeSolverFailure { message, x0, y0, ddx } => write!(
+                f,
+                "the ODE solver failed (x0 = {}, y0 = {}, Δx = {}): {}",
+                x0, y0, ddx, message
+            ),
+            Error::NonFinite { what, x, y } => write!(f, "{} is not finite at (x = {}, y = {})", what, x, y),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_works() {
+        let err = Error::LocalNewtonFailure {
+            x0: 0.1,
+            y0: 0.5,
+            ddx: 0.2,
+            residual: 1e-3,
+            history: vec![1.0, 0.1, 1e-3],
+        };
+        assert_eq!(
+            err.to_string(),
+            "backward Euler did not converge after 3 iterations (x0 = 0.1, y0 = 0.5, Δx = 0.2, residual = 1e-3)"
+        );
+        let err = Error::MissingParameter {
+            model: "Dahlquist".to_string(),
+            name: "lambda".to_string(),
+        };
+        assert_eq!(err.to_string(), "parameter 'lambda' of Dahlquist not found");
+        let err = Error::TooManySubsteps {
+            x0: 0.0,
+            y0: 1.0,
+            ddx: 0.1,
+            pseudo_time: 0.25,
+            n_substep_max: 3,
+        };
+        assert_eq!(
+            err.to_string(),
+            "the maximum number of substeps (3) was reached at T = 0.25 (x0 = 0, y0 = 1, Δx = 0.1)"
+        );
+    }
+}
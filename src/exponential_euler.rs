@@ -0,0 +1,113 @@
+use crate::{Error, LocalIntegrator, ModelTrait};
+
+/// Implements the exponential (Rosenbrock-type) Euler update with its consistent tangent
+///
+/// The linearization of f about (x0, y0) is integrated exactly and the remainder is dropped:
+///
+/// ```text
+/// dy/dx ≈ f0 + L0 (x - x0) + J0 (y - y0)
+/// y1 = y0 + h φ1(h J0) f0 + h² φ2(h J0) L0
+/// ```
+///
+/// where h = Δx, φ1(z) = (eᶻ - 1)/z and φ2(z) = (eᶻ - 1 - z)/z². The update needs no iterations, is
+/// second-order accurate, and is exact for linear models such as Dahlquist's. Since f0, L0 and J0
+/// do not depend on h, the consistent tangent modulus is:
+///
+/// ```text
+/// dy1/dx1 = e^(h J0) f0 + h φ1(h J0) L0
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ExponentialEuler {}
+
+impl ExponentialEuler {
+    /// Allocates a new instance
+    pub fn new() -> Self {
+        ExponentialEuler {}
+    }
+}
+
+impl LocalIntegrator for ExponentialEuler {
+    fn name(&self) -> String {
+        "Exponential Euler".to_string()
+    }
+
+    fn update(&mut self, model: &dyn ModelTrait, x: &mut f64, y: &mut f64, ddx: f64) -> Result<f64, Error> {
+        let (x0, y0, h) = (*x, *y, ddx);
+        let f0 = model.calc_f(x0, y0);
+        let ll0 = model.calc_ll(x0, y0);
+        let jj0 = model.calc_jj(x0, y0);
+        let z = h * jj0;
+        let (phi1, phi2) = phi_functions(z);
+        let y1 = y0 + h * phi1 * f0 + h * h * phi2 * ll0;
+        let ctm = f64::exp(z) * f0 + h * phi1 * ll0;
+        if !y1.is_finite() || !ctm.is_finite() {
+            return Err(Error::NonFinite {
+                what: "the exponential Euler update",
+                x: x0 + h,
+                y: y1,
+            });
+        }
+        *x = x0 + h;
+        *y = y1;
+        Ok(ctm)
+    }
+}
+
+/// Calculates φ1(z) = (eᶻ - 1)/z and φ2(z) = (eᶻ - 1 - z)/z²
+///
+/// Uses the Taylor series φk(z) = Σ zⁿ/(n+k)! for |z| < 1 to avoid cancellation.
+pub(crate) fn phi_functions(z: f64) -> (f64, f64) {
+    if f64::abs(z) < 1.0 {
+        let (mut phi1, mut phi2) = (0.0, 0.0);
+        let mut term1 = 1.0; // zⁿ/(n+1)!
+        let mut term2 = 0.5; // zⁿ/(n+2)!
+        for n in 0..20 {
+            phi1 += term1;
+            phi2 += term2;
+            term1 *= z / ((n + 2) as f64);
+            term2 *= z / ((n + 3) as f64);
+        }
+        (phi1, phi2)
+    } else {
+        let phi1 = f64::exp_m1(z) / z;
+        let phi2 = (phi1 - 1.0) / z;
+        (phi1, phi2)
+    }
+}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dahlquist, DahlquistParams};
+    use russell_lab::approx_eq;
+
+    #[test]
+    fn phi_functions_work() {
+        assert_eq!(phi_functions(0.0), (1.0, 0.5));
+        for z in [-1e-6, 1e-6] {
+            let (phi1, phi2) = phi_functions(z);
+            approx_eq(phi1, 1.0 + z / 2.0 + z * z / 6.0, 1e-15);
+            approx_eq(phi2, 0.5 + z / 6.0 + z * z / 24.0, 1e-15);
+        }
+        for z in [-20.0, -1.0, -0.999, -0.3, 0.3, 0.999, 1.0, 2.0] {
+            let (phi1, phi2) = phi_functions(z);
+            approx_eq(phi1, f64::exp_m1(z) / z, 1e-14);
+            approx_eq(phi2, (f64::exp_m1(z) - z) / (z * z), 1e-14);
+        }
+    }
+
+    #[test]
+    fn update_is_exact_for_dahlquist() {
+        let lambda = 5.0;
+        let model = Dahlquist::new(DahlquistParams::new().with_lambda(lambda)).unwrap();
+        let mut scheme = ExponentialEuler::new();
+        let (mut x, mut y) = (0.0, 1.0);
+        for _ in 0..10 {
+            let ctm = scheme.update(&model, &mut x, &mut y, 0.25).unwrap();
+            approx_eq(y, Dahlquist::analytical_y(lambda, x), 1e-15);
+            approx_eq(ctm, -lambda * y, 1e-15);
+        }
+    }
+}
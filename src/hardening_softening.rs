@@ -1,7 +1,162 @@
-use crate::ModelTrait;
-use crate::StrError;
+use crate::{Error, GenericModel, ModelTrait, ParamInfo, Scalar, check_param_keys, get_param};
+use russell_lab::Complex64;
 use std::collections::HashMap;
 
+/// Holds the parameters of the hardening and softening model
+///
+/// Units: x (strain) is dimensionless and y (stress) has units of stress \[σ\].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HardeningSofteningParams {
+    /// Initial slope (λi) \[σ\]; must be positive
+    pub li: f64,
+
+    /// Reference slope (λr) \[σ\]; second slope, after peak, going down; must be positive
+    pub lr: f64,
+
+    /// Reference ordinate (yr(0)) \[σ\]; stress at zero strain (x=0); must be positive
+    ///
+    /// Note that this is not the same yr0 (which is 0 in this model)
+    pub y0r: f64,
+
+    /// Smoothing parameter (α) \[1/σ\]; when going from λi to λr; must be non-negative
+    pub a: f64,
+
+    /// Smoothing parameter (β) \[1/σ\]; when going from λr to 0; must be positive
+    pub b: f64,
+}
+
+impl HardeningSofteningParams {
+    /// Holds the metadata of the parameters
+    pub const INFO: [ParamInfo; 5] = [
+        ParamInfo {
+            name: "li",
+            description: "initial slope (λi)",
+            units: "σ",
+            default: 10.0,
+            min: 0.0,
+            min_exclusive: true,
+            max: f64::INFINITY,
+        },
+        ParamInfo {
+            name: "lr",
+            description: "reference slope (λr); second slope, after peak, going down",
+            units: "σ",
+            default: 3.0,
+            min: 0.0,
+            min_exclusive: true,
+            max: f64::INFINITY,
+        },
+        ParamInfo {
+            name: "y0r",
+            description: "reference ordinate (yr(0)); stress at zero strain",
+            units: "σ",
+            default: 1.0,
+            min: 0.0,
+            min_exclusive: true,
+            max: f64::INFINITY,
+        },
+        ParamInfo {
+            name: "a",
+            description: "smoothing parameter (α); when going from λi to λr",
+            units: "1/σ",
+            default: 3.0,
+            min: 0.0,
+            min_exclusive: false,
+            max: f64::INFINITY,
+        },
+        ParamInfo {
+            name: "b",
+            description: "smoothing parameter (β); when going from λr to 0",
+            units: "1/σ",
+            default: 5.0,
+            min: 0.0,
+            min_exclusive: true,
+            max: f64::INFINITY,
+        },
+    ];
+
+    /// Allocates a new instance with default values
+    pub fn new() -> Self {
+        HardeningSofteningParams {
+            li: Self::INFO[0].default,
+            lr: Self::INFO[1].default,
+            y0r: Self::INFO[2].default,
+            a: Self::INFO[3].default,
+            b: Self::INFO[4].default,
+        }
+    }
+
+    /// Sets the initial slope (λi)
+    pub fn with_li(mut self, li: f64) -> Self {
+        self.li = li;
+        self
+    }
+
+    /// Sets the reference slope (λr)
+    pub fn with_lr(mut self, lr: f64) -> Self {
+        self.lr = lr;
+        self
+    }
+
+    /// Sets the reference ordinate (yr(0))
+    pub fn with_y0r(mut self, y0r: f64) -> Self {
+        self.y0r = y0r;
+        self
+    }
+
+    /// Sets the smoothing parameter (α)
+    pub fn with_a(mut self, a: f64) -> Self {
+        self.a = a;
+        self
+    }
+
+    /// Sets the smoothing parameter (β)
+    pub fn with_b(mut self, b: f64) -> Self {
+        self.b = b;
+        self
+    }
+
+    /// Allocates a new instance from a map of named values
+    ///
+    /// Returns an error if a parameter is missing or if an unknown parameter is given.
+    pub fn from_map(params: &HashMap<&str, f64>) -> Result<Self, Error> {
+        let model = "HardeningSoftening";
+        check_param_keys(model, params, &Self::INFO)?;
+        Ok(HardeningSofteningParams {
+            li: get_param(model, params, "li")?,
+            lr: get_param(model, params, "lr")?,
+            y0r: get_param(model, params, "y0r")?,
+            a: get_param(model, params, "a")?,
+            b: get_param(model, params, "b")?,
+        })
+    }
+
+    /// Validates the parameters
+    pub fn validate(&self) -> Result<(), Error> {
+        Self::INFO[0].check(self.li)?;
+        Self::INFO[1].check(self.lr)?;
+        Self::INFO[2].check(self.y0r)?;
+        Self::INFO[3].check(self.a)?;
+        Self::INFO[4].check(self.b)?;
+        // c3 = exp(β yr(0)) - 1 must be positive and finite, otherwise yr(x) takes the log of a non-positive number
+        let c3 = f64::exp(self.b * self.y0r) - 1.0;
+        if !(c3 > 0.0 && c3.is_finite()) {
+            return Err(Error::InvalidParameter {
+                name: "b".to_string(),
+                value: self.b,
+                reason: format!("exp(b·y0r) - 1 must be positive and finite (y0r = {})", self.y0r),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Default for HardeningSofteningParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Implements the hardening and softening model
 ///
 /// ```text
@@ -28,19 +183,10 @@ pub struct HardeningSoftening {
 impl HardeningSoftening {
     /// Allocates a new instance
     ///
-    /// # Parameters
-    ///
-    /// * `li` - initial slope (λi)
-    /// * `lr` - reference slope (λr); second slope, after peak, going down
-    /// * `y0r` - reference ordinate (yr(0)); stress at zero strain (x=0). Note that this is not the same yr0 (which is 0 in this model)
-    /// * `a` - smoothing parameter (α); when going from λi to λr
-    /// * `b` - smoothing parameter (β); when going from λr to 0
-    pub fn new(params: HashMap<&str, f64>) -> Result<Self, StrError> {
-        let li = *params.get("li").ok_or("Parameter 'li' not found")?;
-        let lr = *params.get("lr").ok_or("Parameter 'lr' not found")?;
-        let y0r = *params.get("y0r").ok_or("Parameter 'y0r' not found")?;
-        let a = *params.get("a").ok_or("Parameter 'a' not found")?;
-        let b = *params.get("b").ok_or("Parameter 'b' not found")?;
+    /// Returns an error if the parameters are invalid (see [HardeningSofteningParams::validate]).
+    pub fn new(params: HardeningSofteningParams) -> Result<Self, Error> {
+        params.validate()?;
+        let HardeningSofteningParams { li, lr, y0r, a, b } = params;
         let c1 = b * lr;
         let c2 = 1.0; // exp(β yr0) = exp(0) since yr0 = 0
         let c3 = f64::exp(b * y0r) - c2;
@@ -62,24 +208,24 @@ impl HardeningSoftening {
     /// ```text
     /// yr(x) = -λr x + ln(c3 + c2 * exp(c1 * x)) / β
     /// ```
-    fn yr(&self, x: f64) -> f64 {
-        let c1x = self.c1 * x;
-        if c1x >= 500.0 {
-            0.0
+    fn yr<T: Scalar>(&self, x: T) -> T {
+        let c1x = x * self.c1;
+        if c1x.re() >= 500.0 {
+            T::from_f64(0.0)
         } else {
-            -self.lr * x + f64::ln(self.c3 + self.c2 * f64::exp(c1x)) / self.b
+            -x * self.lr + ((c1x.exp() * self.c2) + self.c3).ln() / self.b
         }
     }
 
     /// Calculates the slope of the reference curve dyr/dx
-    fn dyr_dx(&self, x: f64) -> f64 {
-        let c1x = self.c1 * x;
-        if c1x >= 500.0 {
-            0.0
+    fn dyr_dx<T: Scalar>(&self, x: T) -> T {
+        let c1x = x * self.c1;
+        if c1x.re() >= 500.0 {
+            T::from_f64(0.0)
         } else {
-            let ec1x = f64::exp(c1x);
-            let h = self.c3 + self.c2 * ec1x;
-            -self.lr + (self.c1 * self.c2 * ec1x) / (self.b * h)
+            let ec1x = c1x.exp();
+            let h = ec1x * self.c2 + self.c3;
+            ec1x * (self.c1 * self.c2) / (h * self.b) - self.lr
         }
     }
 
@@ -98,6 +244,19 @@ impl HardeningSoftening {
     }
 }
 
+impl GenericModel for HardeningSoftening {
+    /// Calculates dy/dx = f(x,y) for any scalar type
+    ///
+    /// The branch `del = max(0, yr - y)` is taken on the real part, with `del = yr - y` at `y = yr`
+    /// to match the derivatives of [HardeningSoftening::calc_ll] and [HardeningSoftening::calc_jj].
+    fn eval_f<T: Scalar>(&self, x: T, y: T) -> T {
+        let yr = self.yr(x);
+        let del = if (yr - y).re() >= 0.0 { yr - y } else { T::from_f64(0.0) };
+        let lt = self.dyr_dx(x); // λt (target slope controlled by the reference curve)
+        (lt - self.li) * (-del * self.a).exp() + self.li
+    }
+}
+
 impl ModelTrait for HardeningSoftening {
     /// Calculates dy/dx = f(x,y)
     ///
@@ -107,10 +266,7 @@ impl ModelTrait for HardeningSoftening {
     /// dx
     /// ```
     fn calc_f(&self, x: f64, y: f64) -> f64 {
-        let yr = self.yr(x);
-        let del = f64::max(0.0, yr - y);
-        let lt = self.dyr_dx(x); // λt (target slope controlled by the reference curve)
-        self.li + (lt - self.li) * f64::exp(-self.a * del)
+        self.eval_f(x, y)
     }
 
     /// Calculates L = ∂f/∂x
@@ -125,6 +281,9 @@ impl ModelTrait for HardeningSoftening {
         let del = f64::max(0.0, yr - y);
         let lt = self.dyr_dx(x); // λt (target slope controlled by the reference curve)
         let d2 = self.d2yr_dx2(x);
+        if y > yr {
+            return d2; // f = λt above the reference curve
+        }
         f64::exp(-self.a * del) * (d2 + self.a * self.li * lt - self.a * lt * lt)
     }
 
@@ -139,8 +298,21 @@ impl ModelTrait for HardeningSoftening {
         let yr = self.yr(x);
         let del = f64::max(0.0, yr - y);
         let lt = self.dyr_dx(x); // λt (target slope controlled by the reference curve)
+        if y > yr {
+            return 0.0; // f = λt above the reference curve
+        }
         f64::exp(-self.a * del) * self.a * (lt - self.li)
     }
+
+    /// Returns false because L and J are exact
+    fn approximate_derivatives(&self) -> bool {
+        false
+    }
+
+    /// Calculates f(x,y) with complex arguments
+    fn calc_f_complex(&self, x: Complex64, y: Complex64) -> Option<Complex64> {
+        Some(self.eval_f(x, y))
+    }
 }
 
 // tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -152,13 +324,14 @@ mod tests {
 
     #[test]
     fn test_model_derivatives_1() {
-        let model = HardeningSoftening::new(HashMap::from([
-            ("li", 10.0),
-            ("lr", 3.0),
-            ("y0r", 1.0),
-            ("a", 30.0),
-            ("b", 30.0),
-        ]))
+        let model = HardeningSoftening::new(
+            HardeningSofteningParams::new()
+                .with_li(10.0)
+                .with_lr(3.0)
+                .with_y0r(1.0)
+                .with_a(30.0)
+                .with_b(30.0),
+        )
         .unwrap();
 
         let yr = model.yr(0.0);
@@ -188,14 +361,7 @@ mod tests {
 
     #[test]
     fn test_model_derivatives_2() {
-        let model = HardeningSoftening::new(HashMap::from([
-            ("li", 10.0),
-            ("lr", 3.0),
-            ("y0r", 1.0),
-            ("a", 3.0),
-            ("b", 3.0),
-        ]))
-        .unwrap();
+        let model = HardeningSoftening::new(HardeningSofteningParams::new().with_b(3.0)).unwrap();
 
         let args = &mut 0;
         let x_at = 0.0;
@@ -213,4 +379,53 @@ mod tests {
         println!("J = ∂f/∂y: ana = {}, num = {}", ana, num);
         approx_eq(ana, num, 1e-11);
     }
+
+    #[test]
+    fn test_model_derivatives_above_reference_curve() {
+        let model = HardeningSoftening::new(HardeningSofteningParams::new()).unwrap();
+
+        let args = &mut 0;
+        let x_at = 0.5;
+        let y_at = model.yr(x_at) + 0.1;
+
+        // check L = ∂f/∂x
+        let ana = model.calc_ll(x_at, y_at);
+        let num = deriv1_forward7(x_at, args, |x, _| Ok(model.calc_f(x, y_at))).unwrap();
+        println!("L = ∂f/∂x: ana = {}, num = {}", ana, num);
+        approx_eq(ana, num, 1e-10);
+
+        // check J = ∂f/∂y
+        let ana = model.calc_jj(x_at, y_at);
+        let num = deriv1_forward7(y_at, args, |y, _| Ok(model.calc_f(x_at, y))).unwrap();
+        println!("J = ∂f/∂y: ana = {}, num = {}", ana, num);
+        approx_eq(ana, num, 1e-11);
+    }
+
+    #[test]
+    fn validate_captures_errors() {
+        let params = HardeningSofteningParams::new();
+        assert_eq!(params.validate(), Ok(()));
+        assert_eq!(
+            params.with_li(0.0).validate().unwrap_err().to_string(),
+            "parameter 'li' = 0 is invalid: must be finite and positive"
+        );
+        assert_eq!(
+            params.with_a(f64::NAN).validate().unwrap_err().to_string(),
+            "parameter 'a' = NaN is invalid: must be finite and non-negative"
+        );
+        assert_eq!(
+            params.with_b(1e3).with_y0r(1e3).validate().unwrap_err().to_string(),
+            "parameter 'b' = 1000 is invalid: exp(b·y0r) - 1 must be positive and finite (y0r = 1000)"
+        );
+        let mut map = HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]);
+        assert_eq!(HardeningSofteningParams::from_map(&map), Ok(params));
+        map.insert("beta", 5.0);
+        assert_eq!(
+            HardeningSofteningParams::from_map(&map).err(),
+            Some(Error::UnknownParameter {
+                model: "HardeningSoftening".to_string(),
+                name: "beta".to_string(),
+            })
+        );
+    }
 }
@@ -0,0 +1,252 @@
+use crate::{Error, LocalIntegrator, LocalParams, ModelTrait};
+use russell_lab::{Matrix, Vector, solve_lin_sys};
+
+/// Implements implicit Runge-Kutta local integrators (SDIRK and Radau IIA) with their consistent tangents
+///
+/// The stage values Y_i are found by Newton's method applied to (with h = Δx and x_j = x0 + c_j h):
+///
+/// ```text
+/// G_i = Y_i - y0 - h Σ_j a_ij f(x_j, Y_j) = 0
+/// y1 = y0 + h Σ_j b_j f(x_j, Y_j)
+/// ```
+///
+/// Differentiating the stage equations with respect to h yields the consistent tangent modulus:
+///
+/// ```text
+/// (I - h A diag(J)) dY/dh = A (f + h c ∘ L)
+/// dy1/dx1 = Σ_j b_j [f_j + h (c_j L_j + J_j dY_j/dh)]
+/// ```
+///
+/// which reduces to `(f1 + Δx L1) / (1 - Δx J1)` for backward Euler (A = b = c = 1).
+#[derive(Clone, Debug)]
+pub struct ImplicitRungeKutta {
+    name: String,
+    order: usize,
+    aa: Matrix,
+    b: Vec<f64>,
+    c: Vec<f64>,
+    local_params: LocalParams,
+}
+
+impl ImplicitRungeKutta {
+    /// Allocates a new instance given the Butcher tableau
+    ///
+    /// Returns an error if the dimensions are inconsistent.
+    pub fn new(name: &str, order: usize, aa: &[Vec<f64>], b: &[f64], c: &[f64]) -> Result<Self, Error> {
+        let s = b.len();
+        if s == 0 || c.len() != s || aa.len() != s || aa.iter().any(|row| row.len() != s) {
+            return Err(Error::InvalidParameter {
+                name: "n_stage".to_string(),
+                value: s as f64,
+                reason: "the Butcher tableau must have s×s, s and s entries (s ≥ 1)".to_string(),
+            });
+        }
+        Ok(ImplicitRungeKutta {
+            name: name.to_string(),
+            order,
+            aa: Matrix::from(&aa.to_vec()),
+            b: b.to_vec(),
+            c: c.to_vec(),
+            local_params: LocalParams::new(),
+        })
+    }
+
+    /// Allocates the 2-stage, second-order, L-stable SDIRK method (Alexander)
+    pub fn sdirk2() -> Self {
+        let g = 1.0 - 1.0 / f64::sqrt(2.0);
+        ImplicitRungeKutta::new("SDIRK2", 2, &[vec![g, 0.0], vec![1.0 - g, g]], &[1.0 - g, g], &[g, 1.0]).unwrap()
+    }
+
+    /// Allocates the 3-stage, third-order, L-stable SDIRK method (Alexander)
+    pub fn sdirk3() -> Self {
+        let g = 0.435866521508459;
+        let tau = (1.0 + g) / 2.0;
+        let b1 = -(6.0 * g * g - 16.0 * g + 1.0) / 4.0;
+        let b2 = (6.0 * g * g - 20.0 * g + 5.0) / 4.0;
+        ImplicitRungeKutta::new(
+            "SDIRK3",
+            3,
+            &[vec![g, 0.0, 0.0], vec![tau - g, g, 0.0], vec![b1, b2, g]],
+            &[b1, b2, g],
+            &[g, tau, 1.0],
+        )
+        .unwrap()
+    }
+
+    /// Allocates the 2-stage, third-order Radau IIA method
+    pub fn radau_iia2() -> Self {
+        ImplicitRungeKutta::new(
+            "Radau IIA2",
+            3,
+            &[vec![5.0 / 12.0, -1.0 / 12.0], vec![3.0 / 4.0, 1.0 / 4.0]],
+            &[3.0 / 4.0, 1.0 / 4.0],
+            &[1.0 / 3.0, 1.0],
+        )
+        .unwrap()
+    }
+
+    /// Returns the number of stages
+    pub fn n_stage(&self) -> usize {
+        self.b.len()
+    }
+
+    /// Returns the order of accuracy
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// Returns the parameters of the local solver (the tolerances apply to the max norm of the stage residuals)
+    pub fn local_params(&self) -> &LocalParams {
+        &self.local_params
+    }
+
+    /// Calculates the matrix I - h A diag(J)
+    fn iteration_matrix(&self, h: f64, jj: &[f64]) -> Matrix {
+        let s = self.n_stage();
+        let mut mm = Matrix::new(s, s);
+        for i in 0..s {
+            for (j, jj_j) in jj.iter().enumerate() {
+                let delta = if i == j { 1.0 } else { 0.0 };
+                mm.set(i, j, delta - h * self.aa.get(i, j) * jj_j);
+            }
+        }
+        mm
+    }
+}
+
+impl LocalIntegrator for ImplicitRungeKutta {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn update(&mut self, model: &dyn ModelTrait, x: &mut f64, y: &mut f64, ddx: f64) -> Result<f64, Error> {
+        let (x0, y0, h) = (*x, *y, ddx);
+        let s = self.n_stage();
+        let xx: Vec<_> = self.c.iter().map(|c| x0 + c * h).collect();
+
+        // stage values (trial: forward Euler)
+        let f0 = model.calc_f(x0, y0);
+        let mut yy: Vec<_> = self.c.iter().map(|c| y0 + c * h * f0).collect();
+        let mut ff = vec![0.0; s];
+        let mut jj = vec![0.0; s];
+        let params = self.local_params;
+        let mut history = Vec::new();
+        let mut converged = false;
+        let mut norm = f64::NAN;
+        for _ in 0..params.n_iteration_max {
+            for j in 0..s {
+                ff[j] = model.calc_f(xx[j], yy[j]);
+                jj[j] = model.calc_jj(xx[j], yy[j]);
+            }
+            let mut gg = Vector::new(s);
+            for i in 0..s {
+                gg[i] = yy[i] - y0;
+                for (j, f) in ff.iter().enumerate() {
+                    gg[i] -= h * self.aa.get(i, j) * f;
+                }
+            }
+            norm = gg.as_data().iter().fold(0.0, |acc, g| f64::max(acc, f64::abs(*g)));
+            if !norm.is_finite() {
+                return Err(Error::NonFinite {
+                    what: "the stage residual",
+                    x: x0 + h,
+                    y: yy[s - 1],
+                });
+            }
+            history.push(norm);
+            if norm < params.abs_tol + params.rel_tol * f64::abs(yy[s - 1]) {
+                converged = true;
+                break;
+            }
+            let mut mm = self.iteration_matrix(h, &jj);
+            solve_lin_sys(&mut gg, &mut mm).map_err(|e| Error::SingularJacobian(e.to_string()))?;
+            for i in 0..s {
+                yy[i] -= gg[i];
+            }
+        }
+        if !converged {
+            return Err(Error::LocalNewtonFailure {
+                x0,
+                y0,
+                ddx,
+                residual: norm,
+                history,
+            });
+        }
+
+        // update
+        let y1 = y0 + h * self.b.iter().zip(&ff).fold(0.0, |acc, (b, f)| acc + b * f);
+
+        // consistent tangent modulus
+        let mut dyy = Vector::new(s);
+        for i in 0..s {
+            for j in 0..s {
+                let ll = model.calc_ll(xx[j], yy[j]);
+                dyy[i] += self.aa.get(i, j) * (ff[j] + h * self.c[j] * ll);
+            }
+        }
+        let mut mm = self.iteration_matrix(h, &jj);
+        solve_lin_sys(&mut dyy, &mut mm).map_err(|e| Error::SingularJacobian(e.to_string()))?;
+        let mut ctm = 0.0;
+        for j in 0..s {
+            let ll = model.calc_ll(xx[j], yy[j]);
+            ctm += self.b[j] * (ff[j] + h * (self.c[j] * ll + jj[j] * dyy[j]));
+        }
+        *x = x0 + h;
+        *y = y1;
+        Ok(ctm)
+    }
+
+    fn set_local_params(&mut self, params: &LocalParams) {
+        self.local_params = *params;
+    }
+}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dahlquist, DahlquistParams};
+    use russell_lab::approx_eq;
+
+    #[test]
+    fn new_captures_errors() {
+        assert!(ImplicitRungeKutta::new("empty", 1, &[], &[], &[]).is_err());
+        assert!(ImplicitRungeKutta::new("bad", 1, &[vec![1.0, 0.0]], &[1.0], &[1.0]).is_err());
+        assert!(ImplicitRungeKutta::new("bad", 1, &[vec![1.0]], &[1.0], &[1.0, 0.0]).is_err());
+        let radau = ImplicitRungeKutta::radau_iia2();
+        assert_eq!(radau.name(), "Radau IIA2");
+        assert_eq!(radau.n_stage(), 2);
+        assert_eq!(radau.order(), 3);
+    }
+
+    #[test]
+    fn update_matches_the_stability_function() {
+        // Dahlquist: y1 = R(z) y0 with z = -λ Δx
+        let lambda = 5.0;
+        let model = Dahlquist::new(DahlquistParams::new().with_lambda(lambda)).unwrap();
+        let ddx = 0.1;
+        let z = -lambda * ddx;
+        let g = 1.0 - 1.0 / f64::sqrt(2.0);
+        let cases = [
+            (
+                ImplicitRungeKutta::new("BwEuler", 1, &[vec![1.0]], &[1.0], &[1.0]).unwrap(),
+                1.0 / (1.0 - z),
+            ),
+            (
+                ImplicitRungeKutta::sdirk2(),
+                (1.0 + (1.0 - 2.0 * g) * z) / f64::powi(1.0 - g * z, 2),
+            ),
+            (
+                ImplicitRungeKutta::radau_iia2(),
+                (1.0 + z / 3.0) / (1.0 - 2.0 * z / 3.0 + z * z / 6.0),
+            ),
+        ];
+        for (mut scheme, r) in cases {
+            let (mut x, mut y) = (0.0, 2.0);
+            scheme.update(&model, &mut x, &mut y, ddx).unwrap();
+            approx_eq(y, 2.0 * r, 1e-14);
+        }
+    }
+}
@@ -1,13 +1,57 @@
-pub type StrError = &'static str;
-
+mod arc_length;
+mod auto_diff;
+mod bar;
+mod bdf2;
+mod conformance;
 mod dahlquist;
+mod dual;
 pub mod enums;
+mod error;
+mod exponential_euler;
 mod hardening_softening;
+mod implicit_runge_kutta;
+mod loading_protocol;
+mod local_integrator;
+mod local_params;
+mod material_point_driver;
 pub mod model;
 mod model_trait;
+mod numerical_tangent;
+mod param_info;
+mod registry;
+mod rosenbrock;
+mod scalar;
+mod simulation_result;
+mod solvability;
+mod substepping;
+mod taylor_test;
+mod theta_method;
 
+pub use arc_length::*;
+pub use auto_diff::*;
+pub use bar::*;
+pub use bdf2::*;
+pub use conformance::*;
 pub use dahlquist::*;
+pub use dual::*;
 pub use enums::*;
-use hardening_softening::*;
+pub use error::*;
+pub use exponential_euler::*;
+pub use hardening_softening::*;
+pub use implicit_runge_kutta::*;
+pub use loading_protocol::*;
+pub use local_integrator::*;
+pub use local_params::*;
+pub use material_point_driver::*;
 pub use model::*;
-use model_trait::*;
+pub use model_trait::*;
+pub use numerical_tangent::*;
+pub use param_info::*;
+pub use registry::*;
+pub use rosenbrock::*;
+pub use scalar::*;
+pub use simulation_result::*;
+pub use solvability::*;
+pub use substepping::*;
+pub use taylor_test::*;
+pub use theta_method::*;
@@ -0,0 +1,274 @@
+use crate::Error;
+
+/// Defines a strain path as a sequence of strain increments
+///
+/// Implement this trait to drive [crate::Model::simulate_path] with a user-defined path.
+pub trait LoadingProtocol {
+    /// Returns the sequence of strain increments (Δx) starting from `x_ini`
+    fn increments(&self, x_ini: f64) -> Vec<f64>;
+}
+
+/// Explicit list of strain increments
+impl LoadingProtocol for Vec<f64> {
+    fn increments(&self, _x_ini: f64) -> Vec<f64> {
+        self.clone()
+    }
+}
+
+/// Applies `n_steps` constant increments `Δx`
+#[derive(Clone, Copy, Debug)]
+pub struct Monotonic {
+    /// Strain increment
+    pub ddx: f64,
+
+    /// Number of steps
+    pub n_steps: usize,
+}
+
+impl Monotonic {
+    /// Allocates a new instance
+    pub fn new(ddx: f64, n_steps: usize) -> Self {
+        Monotonic { ddx, n_steps }
+    }
+}
+
+impl LoadingProtocol for Monotonic {
+    fn increments(&self, _x_ini: f64) -> Vec<f64> {
+        vec![self.ddx; self.n_steps]
+    }
+}
+
+/// Keeps the strain constant for `n_steps` (zero increments)
+#[derive(Clone, Copy, Debug)]
+pub struct Hold {
+    /// Number of steps
+    pub n_steps: usize,
+}
+
+impl Hold {
+    /// Allocates a new instance
+    pub fn new(n_steps: usize) -> Self {
+        Hold { n_steps }
+    }
+}
+
+impl LoadingProtocol for Hold {
+    fn increments(&self, _x_ini: f64) -> Vec<f64> {
+        vec![0.0; self.n_steps]
+    }
+}
+
+/// Piecewise-linear strain history
+///
+/// Each segment goes linearly from the previous strain to a target strain in a number of equal steps.
+/// A segment with zero steps is ignored; a segment whose target equals the previous strain is a hold.
+#[derive(Clone, Debug)]
+pub struct PiecewiseLinear {
+    /// Target strain and number of steps of each segment
+    pub segments: Vec<(f64, usize)>,
+}
+
+impl PiecewiseLinear {
+    /// Allocates a new instance from `(target strain, number of steps)` pairs
+    pub fn new(segments: &[(f64, usize)]) -> Self {
+        PiecewiseLinear {
+            segments: segments.to_vec(),
+        }
+    }
+
+    /// Allocates a new instance from a list of target strains and a maximum increment
+    ///
+    /// The number of steps of each segment is the smallest one such that |Δx| ≤ `ddx_max`,
+    /// with the first segment starting at `x_ini`.
+    pub fn with_max_increment(x_ini: f64, targets: &[f64], ddx_max: f64) -> Result<Self, Error> {
+        if ddx_max.is_nan() || ddx_max <= 0.0 {
+            return Err(Error::InvalidLoading(format!(
+                "the maximum increment must be positive (ddx_max = {})",
+                ddx_max
+            )));
+        }
+        let mut x = x_ini;
+        let segments = targets
+            .iter()
+            .map(|&target| {
+                let n_steps = usize::max(1, f64::ceil(f64::abs(target - x) / ddx_max) as usize);
+                x = target;
+                (target, n_steps)
+            })
+            .collect();
+        Ok(PiecewiseLinear { segments })
+    }
+
+    /// Allocates a new instance from a table with two columns: target strain and number of steps
+    ///
+    /// Blank lines and lines starting with `#` are ignored. For example:
+    ///
+    /// ```text
+    /// # x_target  n_steps
+    ///   0.02      10
+    ///   0.02      5
+    ///  -0.01      30
+    /// ```
+    pub fn from_table(table: &str) -> Result<Self, Error> {
+        let mut segments = Vec::new();
+        for (i, line) in table.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let columns: Vec<_> = line.split_whitespace().collect();
+            if columns.len() != 2 {
+                return Err(Error::InvalidLoading(format!("line {} must have two columns", i + 1)));
+            }
+            let target = columns[0]
+                .parse::<f64>()
+                .map_err(|_| Error::InvalidLoading(format!("cannot parse the target strain in line {}", i + 1)))?;
+            let n_steps = columns[1]
+                .parse::<usize>()
+                .map_err(|_| Error::InvalidLoading(format!("cannot parse the number of steps in line {}", i + 1)))?;
+            segments.push((target, n_steps));
+        }
+        Ok(PiecewiseLinear { segments })
+    }
+}
+
+impl LoadingProtocol for PiecewiseLinear {
+    fn increments(&self, x_ini: f64) -> Vec<f64> {
+        let mut x = x_ini;
+        let mut increments = Vec::new();
+        for &(target, n_steps) in &self.segments {
+            if n_steps == 0 {
+                continue;
+            }
+            let ddx = (target - x) / (n_steps as f64);
+            increments.extend(std::iter::repeat_n(ddx, n_steps));
+            x = target;
+        }
+        increments
+    }
+}
+
+/// Cyclic loading with (possibly growing) amplitudes about the initial strain
+///
+/// Each cycle goes `x_ini → x_ini + A → x_ini - A → x_ini` with `n_steps` per quarter of cycle.
+#[derive(Clone, Debug)]
+pub struct Cyclic {
+    /// Amplitude of each cycle
+    pub amplitudes: Vec<f64>,
+
+    /// Number of steps per quarter of cycle
+    pub n_steps: usize,
+}
+
+impl Cyclic {
+    /// Allocates a new instance
+    pub fn new(amplitudes: &[f64], n_steps: usize) -> Self {
+        Cyclic {
+            amplitudes: amplitudes.to_vec(),
+            n_steps,
+        }
+    }
+}
+
+impl LoadingProtocol for Cyclic {
+    fn increments(&self, x_ini: f64) -> Vec<f64> {
+        let mut segments = Vec::new();
+        for &amplitude in &self.amplitudes {
+            segments.push((x_ini + amplitude, self.n_steps));
+            segments.push((x_ini - amplitude, 2 * self.n_steps));
+            segments.push((x_ini, self.n_steps));
+        }
+        PiecewiseLinear { segments }.increments(x_ini)
+    }
+}
+
+/// Concatenates several protocols
+pub struct Sequence {
+    /// Protocols applied one after another
+    pub protocols: Vec<Box<dyn LoadingProtocol>>,
+}
+
+impl Sequence {
+    /// Allocates a new (empty) instance
+    pub fn new() -> Self {
+        Sequence { protocols: Vec::new() }
+    }
+
+    /// Appends a protocol to the sequence
+    pub fn then<P: LoadingProtocol + 'static>(mut self, protocol: P) -> Self {
+        self.protocols.push(Box::new(protocol));
+        self
+    }
+}
+
+impl Default for Sequence {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LoadingProtocol for Sequence {
+    fn increments(&self, x_ini: f64) -> Vec<f64> {
+        let mut x = x_ini;
+        let mut increments = Vec::new();
+        for protocol in &self.protocols {
+            let more = protocol.increments(x);
+            x += more.iter().sum::<f64>();
+            increments.extend(more);
+        }
+        increments
+    }
+}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use russell_lab::array_approx_eq;
+
+    #[test]
+    fn simple_protocols_work() {
+        assert_eq!(Monotonic::new(0.1, 3).increments(0.0), &[0.1, 0.1, 0.1]);
+        assert_eq!(Hold::new(2).increments(1.0), &[0.0, 0.0]);
+        assert_eq!(vec![0.1, -0.2].increments(0.0), &[0.1, -0.2]);
+    }
+
+    #[test]
+    fn piecewise_linear_works() {
+        let path = PiecewiseLinear::new(&[(0.2, 2), (0.2, 1), (0.0, 4), (0.5, 0)]);
+        array_approx_eq(
+            &path.increments(0.0),
+            &[0.1, 0.1, 0.0, -0.05, -0.05, -0.05, -0.05],
+            1e-15,
+        );
+
+        let path = PiecewiseLinear::with_max_increment(0.0, &[0.3, -0.1], 0.1).unwrap();
+        assert_eq!(path.segments, &[(0.3, 3), (-0.1, 4)]);
+        assert!(PiecewiseLinear::with_max_increment(0.0, &[0.3], 0.0).is_err());
+
+        let table = "# x_target n_steps\n 0.2 2\n\n 0.0 4\n";
+        let path = PiecewiseLinear::from_table(table).unwrap();
+        assert_eq!(path.segments, &[(0.2, 2), (0.0, 4)]);
+        assert_eq!(
+            PiecewiseLinear::from_table("0.2 two").unwrap_err().to_string(),
+            "invalid loading protocol: cannot parse the number of steps in line 1"
+        );
+    }
+
+    #[test]
+    fn cyclic_and_sequence_work() {
+        let path = Cyclic::new(&[0.1, 0.2], 1);
+        array_approx_eq(
+            &path.increments(1.0),
+            &[0.1, -0.1, -0.1, 0.1, 0.2, -0.2, -0.2, 0.2],
+            1e-14,
+        );
+
+        let path = Sequence::new()
+            .then(Monotonic::new(0.1, 2))
+            .then(Hold::new(1))
+            .then(PiecewiseLinear::new(&[(0.0, 2)]));
+        array_approx_eq(&path.increments(0.0), &[0.1, 0.1, 0.0, -0.1, -0.1], 1e-15);
+    }
+}
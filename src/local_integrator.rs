@@ -0,0 +1,41 @@
+use crate::{Error, LocalParams, ModelTrait};
+
+/// Defines a local (single-increment) integration scheme of `dy/dx = f(x, y)` with its consistent tangent
+///
+/// Implementations update `(x0, y0)` to `(x1, y1)` with `x1 = x0 + Δx` and return the consistent
+/// tangent modulus `dy1/dx1 = dy1/dΔx` of the scheme. Add them to [crate::Model::add_integrator] to
+/// have [crate::Model::simulate] report their results beside backward Euler.
+pub trait LocalIntegrator {
+    /// Returns a name identifying the scheme (and its parameters)
+    fn name(&self) -> String;
+
+    /// Updates x and y with the strain increment `Δx` and returns the consistent tangent modulus
+    fn update(&mut self, model: &dyn ModelTrait, x: &mut f64, y: &mut f64, ddx: f64) -> Result<f64, Error>;
+
+    /// Clears the history (if any) before a new path is integrated
+    fn reset(&mut self) {}
+
+    /// Sets the parameters of the local solver (if the scheme is implicit)
+    ///
+    /// [crate::Model] calls this method with the parameters given to [crate::Model::set_local_params]
+    /// before performing the updates.
+    fn set_local_params(&mut self, _params: &LocalParams) {}
+}
+
+/// Solves the scalar equation r(y) = 0 with the local solver (see [LocalParams])
+///
+/// The `residual` function returns `(r, dr/dy)`. Returns the root and the number of iterations.
+pub(crate) fn newton_scalar<F>(
+    params: &LocalParams,
+    x0: f64,
+    y0: f64,
+    ddx: f64,
+    y_trial: f64,
+    residual: F,
+) -> Result<(f64, usize), Error>
+where
+    F: FnMut(f64) -> (f64, f64),
+{
+    let (y, n_iterations, _) = params.solve(x0, y0, ddx, "the local residual", y_trial, residual)?;
+    Ok((y, n_iterations))
+}
@@ -0,0 +1,230 @@
+use crate::Error;
+
+/// Holds the parameters of the local (scalar) solver of the implicit updates
+///
+/// The residual `r(y)` (e.g., `r(y) = y - y0 - Δx f(x1, y)` for backward Euler) is solved by a
+/// safeguarded Newton method:
+///
+/// * before the iterations, a bracket [a, b] with a sign change of r is searched by stepping from the
+///   trial value towards the root with doubling step sizes (if enabled);
+/// * each Newton step is followed by a backtracking line search on |r| (if enabled);
+/// * the bracket is narrowed by the Newton iterates (or found by them if the initial search failed);
+/// * if the slope dr/dy vanishes or the Newton step leaves the bracket, bisection is used instead.
+///
+/// Without a bracket (e.g., if r does not change sign within the searched range), the safeguard
+/// reduces to the line search.
+///
+/// The iterations stop when `|r| < abs_tol + rel_tol |y|`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LocalParams {
+    /// Absolute tolerance on the residual
+    pub abs_tol: f64,
+
+    /// Relative tolerance on the residual (scaled by |y|)
+    pub rel_tol: f64,
+
+    /// Maximum number of iterations
+    pub n_iteration_max: usize,
+
+    /// Enables the backtracking line search
+    pub line_search: bool,
+
+    /// Maximum number of step halvings of the line search
+    pub n_line_search_max: usize,
+
+    /// Maximum number of step doublings of the initial bracketing search (zero disables the search)
+    pub n_bracket_max: usize,
+}
+
+impl LocalParams {
+    /// Allocates a new instance with default values
+    pub fn new() -> Self {
+        LocalParams {
+            abs_tol: 1e-8,
+            rel_tol: 0.0,
+            n_iteration_max: 20,
+            line_search: true,
+            n_line_search_max: 10,
+            n_bracket_max: 30,
+        }
+    }
+
+    /// Validates the parameters
+    pub fn validate(&self) -> Result<(), Error> {
+        let invalid = |name: &str, value: f64, reason: &str| {
+            Err(Error::InvalidParameter {
+                name: name.to_string(),
+                value,
+                reason: reason.to_string(),
+            })
+        };
+        if self.abs_tol.is_nan() || self.abs_tol < 0.0 {
+            return invalid("abs_tol", self.abs_tol, "the tolerances must be non-negative");
+        }
+        if self.rel_tol.is_nan() || self.rel_tol < 0.0 {
+            return invalid("rel_tol", self.rel_tol, "the tolerances must be non-negative");
+        }
+        if self.abs_tol == 0.0 && self.rel_tol == 0.0 {
+            return invalid("abs_tol", self.abs_tol, "at least one tolerance must be positive");
+        }
+        if self.n_iteration_max == 0 {
+            return invalid("n_iteration_max", 0.0, "must be at least 1");
+        }
+        Ok(())
+    }
+
+    /// Solves the scalar equation r(y) = 0 starting from `y_trial`
+    ///
+    /// The `residual` function returns `(r, dr/dy)`; `(x0, y0, ddx)` and `what` are only used to report errors.
+    /// Returns the root, the number of iterations and the final residual.
+    pub(crate) fn solve<F>(
+        &self,
+        x0: f64,
+        y0: f64,
+        ddx: f64,
+        what: &'static str,
+        y_trial: f64,
+        mut residual: F,
+    ) -> Result<(f64, usize, f64), Error>
+    where
+        F: FnMut(f64) -> (f64, f64),
+    {
+        let mut y = y_trial;
+        let (mut r, mut dr) = residual(y);
+        if !r.is_finite() {
+            return Err(Error::NonFinite { what, x: x0 + ddx, y });
+        }
+        let mut bracket = Bracket::default();
+        if f64::abs(r) >= self.abs_tol + self.rel_tol * f64::abs(y) {
+            bracket.search(y, r, dr, self.n_bracket_max, &mut residual);
+        }
+        let mut history = Vec::new();
+        let mut r_last = r;
+        for _ in 0..self.n_iteration_max {
+            history.push(f64::abs(r));
+            r_last = r;
+            if f64::abs(r) < self.abs_tol + self.rel_tol * f64::abs(y) {
+                return Ok((y, history.len() - 1, r));
+            }
+            bracket.update(y, r);
+
+            // Newton step with backtracking line search
+            let mut next = None;
+            if dr != 0.0 && dr.is_finite() {
+                let dy = -r / dr;
+                let mut alpha = 1.0;
+                let n_trial = if self.line_search {
+                    self.n_line_search_max + 1
+                } else {
+                    1
+                };
+                for _ in 0..n_trial {
+                    let y_new = y + alpha * dy;
+                    if !bracket.contains(y_new) {
+                        break;
+                    }
+                    let (r_new, dr_new) = residual(y_new);
+                    if r_new.is_finite() {
+                        bracket.update(y_new, r_new);
+                        next = Some((y_new, r_new, dr_new));
+                        if !self.line_search || f64::abs(r_new) <= (1.0 - 1e-4 * alpha) * f64::abs(r) {
+                            break;
+                        }
+                    }
+                    alpha /= 2.0;
+                }
+            }
+
+            // bisection if the Newton step leaves the bracket or does not reduce the residual
+            if let Some((a, b)) = bracket.interval()
+                && next.is_none_or(|(_, r_new, _)| f64::abs(r_new) >= f64::abs(r))
+            {
+                let y_new = (a + b) / 2.0;
+                let (r_new, dr_new) = residual(y_new);
+                next = Some((y_new, r_new, dr_new));
+            }
+            match next {
+                Some((y_new, r_new, dr_new)) if r_new.is_finite() => (y, r, dr) = (y_new, r_new, dr_new),
+                Some((y_new, _, _)) => {
+                    return Err(Error::NonFinite {
+                        what,
+                        x: x0 + ddx,
+                        y: y_new,
+                    });
+                }
+                None => break,
+            }
+        }
+        Err(Error::LocalNewtonFailure {
+            x0,
+            y0,
+            ddx,
+            residual: r_last,
+            history,
+        })
+    }
+}
+
+impl Default for LocalParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Holds the latest points with negative and positive residuals
+#[derive(Default)]
+struct Bracket {
+    negative: Option<f64>,
+    positive: Option<f64>,
+}
+
+impl Bracket {
+    /// Records the point y according to the sign of its residual r
+    fn update(&mut self, y: f64, r: f64) {
+        if r < 0.0 {
+            self.negative = Some(y);
+        } else if r > 0.0 {
+            self.positive = Some(y);
+        }
+    }
+
+    /// Searches a sign change of r by stepping from y towards the root with doubling step sizes
+    ///
+    /// The direction follows the slope dr/dy (downhill on |r|) and the first step is |r|.
+    fn search<F>(&mut self, y: f64, r: f64, dr: f64, n_max: usize, residual: &mut F)
+    where
+        F: FnMut(f64) -> (f64, f64),
+    {
+        self.update(y, r);
+        let direction = if dr < 0.0 { r.signum() } else { -r.signum() };
+        let mut step = f64::max(f64::abs(r), f64::EPSILON * f64::max(f64::abs(y), 1.0));
+        for _ in 0..n_max {
+            let y_new = y + direction * step;
+            let (r_new, _) = residual(y_new);
+            if !r_new.is_finite() {
+                return;
+            }
+            if r_new.signum() != r.signum() {
+                self.update(y_new, r_new);
+                return;
+            }
+            step *= 2.0;
+        }
+    }
+
+    /// Returns the interval (if the sign change has been found)
+    fn interval(&self) -> Option<(f64, f64)> {
+        match (self.negative, self.positive) {
+            (Some(a), Some(b)) => Some((f64::min(a, b), f64::max(a, b))),
+            _ => None,
+        }
+    }
+
+    /// Returns true if y is inside the closed interval (or if there is no interval yet)
+    fn contains(&self, y: f64) -> bool {
+        match self.interval() {
+            Some((a, b)) => y >= a && y <= b,
+            None => true,
+        }
+    }
+}
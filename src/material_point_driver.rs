@@ -0,0 +1,230 @@
+use crate::{Error, Model};
+
+/// Selects the Jacobian (stiffness) used by the Newton iterations of the material point driver
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JacobianKind {
+    /// Consistent tangent modulus dy1/dΔx (quadratic convergence)
+    Consistent,
+
+    /// Continuous modulus f(x1, y1) at the current iterate
+    Continuous,
+
+    /// Secant (quasi-Newton) slope through the last two iterates (superlinear convergence)
+    ///
+    /// Note that the "total" secant (y1 - y0) / Δx equals f(x1, y1) for backward Euler, hence it is not an option.
+    Secant,
+
+    /// Continuous modulus f(x0, y0) at the beginning of the step (modified Newton)
+    Initial,
+}
+
+/// Defines how a loading step is controlled
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Control {
+    /// Prescribed strain increment Δx
+    Strain(f64),
+
+    /// Prescribed stress increment Δy
+    Stress(f64),
+}
+
+/// Holds the results of one step of the material point driver
+#[derive(Clone, Debug)]
+pub struct DriverStep {
+    /// Control of the step
+    pub control: Control,
+
+    /// Strain increment (prescribed or computed)
+    pub ddx: f64,
+
+    /// Strain at the end of the step
+    pub x: f64,
+
+    /// Stress at the end of the step
+    pub y: f64,
+
+    /// Consistent tangent modulus at the end of the step
+    pub ctm: f64,
+
+    /// Number of Newton iterations (zero for strain-controlled steps)
+    pub n_iterations: usize,
+
+    /// Absolute value of the stress residual at each iteration (empty for strain-controlled steps)
+    pub residuals: Vec<f64>,
+}
+
+impl DriverStep {
+    /// Returns the observed convergence rates (see [convergence_rates])
+    pub fn rates(&self) -> Vec<f64> {
+        convergence_rates(&self.residuals)
+    }
+}
+
+/// Implements a stress-controlled and mixed-controlled material point driver
+///
+/// For a prescribed stress increment Δy, the strain increment is found with Newton's method:
+///
+/// ```text
+/// R(Δx) = y1(Δx) - (y0 + Δy) = 0
+/// Δx ← Δx - R / K
+/// ```
+///
+/// where `y1(Δx)` is given by [Model::backward_euler_update] and K is selected by [JacobianKind].
+/// With `K = dy1/dΔx` (the consistent tangent modulus) the convergence is quadratic.
+///
+/// Since `y1(Δx)` is only accurate up to the tolerance of the local solver (see [crate::LocalParams]),
+/// the stress tolerance is not allowed to be below this noise level (see [MaterialPointDriver::effective_tolerance]).
+#[derive(Clone, Copy, Debug)]
+pub struct MaterialPointDriver {
+    /// Jacobian used by the Newton iterations
+    pub jacobian: JacobianKind,
+
+    /// Tolerance on the absolute value of the stress residual (see [MaterialPointDriver::effective_tolerance])
+    pub tolerance: f64,
+
+    /// Maximum number of iterations
+    pub n_iteration_max: usize,
+}
+
+impl MaterialPointDriver {
+    /// Allocates a new instance
+    pub fn new(jacobian: JacobianKind) -> Self {
+        MaterialPointDriver {
+            jacobian,
+            tolerance: 1e-10,
+            n_iteration_max: 100,
+        }
+    }
+
+    /// Returns the tolerance on the stress residual used with the model and the target stress
+    ///
+    /// The local solver stops when `|r| < abs_tol + rel_tol |y|`; thus, the tolerance of the stress residual
+    /// is bounded below by ten times this value to avoid stalling on the noise of the updates:
+    ///
+    /// ```text
+    /// tol = max(tolerance, 10 (abs_tol + rel_tol |y_target|))
+    /// ```
+    pub fn effective_tolerance(&self, model: &Model, y_target: f64) -> f64 {
+        let local = model.local_params();
+        f64::max(
+            self.tolerance,
+            10.0 * (local.abs_tol + local.rel_tol * f64::abs(y_target)),
+        )
+    }
+
+    /// Performs a stress-controlled step
+    ///
+    /// Updates `x` and `y` such that `y_new = y_old + Δy`.
+    pub fn stress_step(&self, model: &Model, x: &mut f64, y: &mut f64, ddy: f64) -> Result<DriverStep, Error> {
+        let x0 = *x;
+        let y0 = *y;
+        let y_target = y0 + ddy;
+        let tolerance = self.effective_tolerance(model, y_target);
+        let kk0 = model.continuous_modulus(x0, y0);
+        let mut ddx = if kk0 != 0.0 { ddy / kk0 } else { 0.0 };
+        let mut residuals = Vec::new();
+        let mut previous: Option<(f64, f64)> = None; // (Δx, R) of the previous iterate
+        for _ in 0..=self.n_iteration_max {
+            let mut x1 = x0;
+            let mut y1 = y0;
+            let stats = model.backward_euler_update(&mut x1, &mut y1, ddx)?;
+            let r = y1 - y_target;
+            residuals.push(f64::abs(r));
+            if f64::abs(r) < tolerance {
+                *x = x1;
+                *y = y1;
+                return Ok(DriverStep {
+                    control: Control::Stress(ddy),
+                    ddx,
+                    x: x1,
+                    y: y1,
+                    ctm: stats.ctm,
+                    n_iterations: residuals.len() - 1,
+                    residuals,
+                });
+            }
+            let kk = match self.jacobian {
+                JacobianKind::Consistent => stats.ctm,
+                JacobianKind::Continuous => model.continuous_modulus(x1, y1),
+                JacobianKind::Secant => match previous {
+                    Some((ddx_prev, r_prev)) if ddx != ddx_prev => (r - r_prev) / (ddx - ddx_prev),
+                    _ => kk0,
+                },
+                JacobianKind::Initial => kk0,
+            };
+            previous = Some((ddx, r));
+            if kk == 0.0 || !kk.is_finite() {
+                return Err(Error::SingularJacobian(format!(
+                    "the material point stiffness is {} at (x = {}, y = {})",
+                    kk, x1, y1
+                )));
+            }
+            ddx -= r / kk;
+        }
+        Err(Error::GlobalNewtonFailure {
+            residual: *residuals.last().unwrap(),
+            history: residuals,
+        })
+    }
+
+    /// Performs a strain-controlled step
+    pub fn strain_step(&self, model: &Model, x: &mut f64, y: &mut f64, ddx: f64) -> Result<DriverStep, Error> {
+        let stats = model.backward_euler_update(x, y, ddx)?;
+        Ok(DriverStep {
+            control: Control::Strain(ddx),
+            ddx,
+            x: *x,
+            y: *y,
+            ctm: stats.ctm,
+            n_iterations: 0,
+            residuals: Vec::new(),
+        })
+    }
+
+    /// Runs a sequence of strain- and/or stress-controlled steps starting from `(x_ini, y_ini)`
+    pub fn run(&self, model: &Model, x_ini: f64, y_ini: f64, controls: &[Control]) -> Result<Vec<DriverStep>, Error> {
+        let mut x = x_ini;
+        let mut y = y_ini;
+        controls
+            .iter()
+            .map(|control| match *control {
+                Control::Strain(ddx) => self.strain_step(model, &mut x, &mut y, ddx),
+                Control::Stress(ddy) => self.stress_step(model, &mut x, &mut y, ddy),
+            })
+            .collect()
+    }
+}
+
+/// Calculates the observed convergence rates from a sequence of residuals
+///
+/// ```text
+///       ln(r[k+1] / r[k])
+/// q = ─────────────────────
+///       ln(r[k] / r[k-1])
+/// ```
+///
+/// Returns one value per triplet of consecutive non-zero residuals.
+pub fn convergence_rates(residuals: &[f64]) -> Vec<f64> {
+    residuals
+        .windows(3)
+        .filter(|r| r[0] > 0.0 && r[1] > 0.0 && r[2] > 0.0 && r[0] != r[1])
+        .map(|r| f64::ln(r[2] / r[1]) / f64::ln(r[1] / r[0]))
+        .collect()
+}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use russell_lab::array_approx_eq;
+
+    #[test]
+    fn convergence_rates_works() {
+        let rates = convergence_rates(&[1e-1, 1e-2, 1e-4, 1e-8, 0.0]);
+        array_approx_eq(&rates, &[2.0, 2.0], 1e-14);
+        let rates = convergence_rates(&[1e-1, 1e-2, 1e-3]);
+        array_approx_eq(&rates, &[1.0], 1e-14);
+        assert_eq!(convergence_rates(&[1.0, 0.1]).len(), 0);
+    }
+}
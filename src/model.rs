@@ -1,13 +1,17 @@
-use crate::StrError;
-use crate::{Dahlquist, HardeningSoftening, ModelTrait, ModelType};
-use russell_lab::Vector;
-use russell_ode::{Method, OdeSolver, Params, System};
+use crate::{
+    DifferenceScheme, Error, IntegratorResult, LoadingProtocol, LocalIntegrator, LocalParams, ModelTrait, ModelType,
+    Monotonic, NumericalTangent, SimulationResult, Solvability, SolvabilityParams, StepRecord, TaylorTest,
+    allocate_builtin,
+};
+use russell_lab::{Complex64, Vector};
+use russell_ode::{OdeSolver, Params, Stats, System};
+use russell_sparse::Sym;
 use std::collections::HashMap;
 use std::sync::Arc;
 
-const N_ITERATIONS_MAX: usize = 20;
-const BE_TOLERANCE: f64 = 1e-8;
 const DELTA: f64 = 1e-5;
+const COMPLEX_STEP: f64 = 1e-30;
+const TAYLOR_N: usize = 8;
 
 pub struct ArgsForODE {
     model: Arc<dyn ModelTrait>,
@@ -15,80 +19,380 @@ pub struct ArgsForODE {
     ddx: f64,
 }
 
+/// Holds statistics of the backward Euler update
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BackwardEulerStats {
+    /// Number of Newton iterations
+    pub n_iterations: usize,
+
+    /// Final residual
+    pub residual: f64,
+
+    /// Number of substeps (one if the increment has not been split)
+    pub n_substeps: usize,
+
+    /// Consistent tangent modulus dy1/dx1 of the whole increment (chained across the substeps)
+    pub ctm: f64,
+}
+
 /// Represents a stress-strain model with x being strain and y being stress
 pub struct Model<'a> {
     actual: Arc<dyn ModelTrait>,
     ode_solver: OdeSolver<'a, ArgsForODE>,
+    sensitivity_solver: OdeSolver<'a, ArgsForODE>,
+    integrators: Vec<Box<dyn LocalIntegrator>>,
+    min_substep: f64,
+    local_params: LocalParams,
+    solvability_params: Option<SolvabilityParams>,
+    num_ctm_scheme: DifferenceScheme,
+    num_ctm_step: Option<f64>,
+    ode_sensitivity: bool,
+    ode_params: Params,
+    ode_tolerances: Option<(f64, f64)>,
 }
 
 impl<'a> Model<'a> {
-    /// Allocates a new instance
-    pub fn new(model_type: ModelType, params: HashMap<&str, f64>, ode_method: Method) -> Result<Self, StrError> {
-        let actual: Arc<dyn ModelTrait> = match model_type {
-            ModelType::Dahlquist => Arc::new(Dahlquist::new(params)?),
-            ModelType::HardeningSoftening => Arc::new(HardeningSoftening::new(params)?),
-        };
-        let ode_params = Params::new(ode_method);
-        let ode_system = System::new(1, |f, t, y, args: &mut ArgsForODE| {
+    /// Allocates a new instance with one of the built-in models
+    ///
+    /// The ODE solver is configured by `ode_params`, e.g., `Params::new(Method::DoPri5)`.
+    pub fn new(model_type: ModelType, params: HashMap<&str, f64>, ode_params: Params) -> Result<Self, Error> {
+        let actual = allocate_builtin(model_type, params)?;
+        Model::from_model(actual, ode_params)
+    }
+
+    /// Allocates a new instance with a user-defined model
+    ///
+    /// See also [crate::ModelRegistry] to allocate models by name.
+    ///
+    /// The results are flagged as approximate ([SimulationResult::approximate_tangent]) unless the model
+    /// overrides [ModelTrait::approximate_derivatives] to return false; models with exact `calc_ll` and
+    /// `calc_jj` must do so (the default is true).
+    pub fn from_model(actual: Arc<dyn ModelTrait>, ode_params: Params) -> Result<Self, Error> {
+        let mut ode_system = System::new(1, |f, t, y, args: &mut ArgsForODE| {
             // normalize: x(t) = x0 + t * Δx  thus  dx/dt = Δx
             // solve: dy/dt = dy/dx * dx/dt = f(x,y) * Δx
             let x = args.x0 + t * args.ddx;
             f[0] = args.model.calc_f(x, y[0]) * args.ddx;
             Ok(())
         });
-        let ode_solver = OdeSolver::new(ode_params, ode_system)?;
-        Ok(Model { actual, ode_solver })
+        ode_system
+            .set_jacobian(Some(1), Sym::No, |jj, alpha, t, y, args: &mut ArgsForODE| {
+                // ∂(f Δx)/∂y = J Δx
+                let x = args.x0 + t * args.ddx;
+                jj.reset();
+                jj.put(0, 0, alpha * args.model.calc_jj(x, y[0]) * args.ddx)
+            })
+            .map_err(Error::OdeSolverSetup)?;
+        let ode_solver = OdeSolver::new(ode_params, ode_system).map_err(Error::OdeSolverSetup)?;
+        let sensitivity_system = System::new(2, |f, t, y, args: &mut ArgsForODE| {
+            // augment with s = ∂y/∂Δx  thus  ds/dt = ∂(f Δx)/∂Δx = f + Δx (L t + J s)
+            let x = args.x0 + t * args.ddx;
+            let fx = args.model.calc_f(x, y[0]);
+            let ll = args.model.calc_ll(x, y[0]);
+            let jj = args.model.calc_jj(x, y[0]);
+            f[0] = fx * args.ddx;
+            f[1] = fx + args.ddx * (ll * t + jj * y[1]);
+            Ok(())
+        });
+        let sensitivity_solver = OdeSolver::new(ode_params, sensitivity_system).map_err(Error::OdeSolverSetup)?;
+        Ok(Model {
+            actual,
+            ode_solver,
+            sensitivity_solver,
+            integrators: Vec::new(),
+            min_substep: 1.0,
+            local_params: LocalParams::new(),
+            solvability_params: None,
+            num_ctm_scheme: DifferenceScheme::Forward,
+            num_ctm_step: Some(DELTA),
+            ode_sensitivity: false,
+            ode_params,
+            ode_tolerances: None,
+        })
+    }
+
+    /// Returns the underlying stress-strain model
+    pub fn actual(&self) -> &dyn ModelTrait {
+        self.actual.as_ref()
+    }
+
+    /// Returns the statistics of the last call to the ODE solver
+    pub fn ode_stats(&self) -> &Stats {
+        self.ode_solver.stats()
+    }
+
+    /// Sets the absolute and relative tolerances of the ODE solvers
+    ///
+    /// The tolerances are also used as the noise level of the ODE route by [Model::numerical_tangent].
+    /// The tolerances given through the `Params` of the constructor cannot be queried; thus, the automatic
+    /// step of the ODE route requires this function to be called.
+    pub fn set_ode_tolerances(&mut self, abs_tol: f64, rel_tol: f64) -> Result<&mut Self, Error> {
+        self.ode_params
+            .set_tolerances(abs_tol, rel_tol, None)
+            .map_err(Error::OdeSolverSetup)?;
+        self.ode_solver
+            .update_params(self.ode_params)
+            .map_err(Error::OdeSolverSetup)?;
+        self.sensitivity_solver
+            .update_params(self.ode_params)
+            .map_err(Error::OdeSolverSetup)?;
+        self.ode_tolerances = Some((abs_tol, rel_tol));
+        Ok(self)
+    }
+
+    /// Sets the minimum substep (fraction of the increment) of the backward Euler update
+    ///
+    /// If the local Newton iterations do not converge, [Model::backward_euler_update] halves the
+    /// substep recursively until it converges or becomes smaller than `min_substep`. The default
+    /// value of 1.0 disables substepping.
+    ///
+    /// Returns an error if `min_substep` is not in (0, 1].
+    pub fn set_min_substep(&mut self, min_substep: f64) -> Result<&mut Self, Error> {
+        if min_substep.is_nan() || min_substep <= 0.0 || min_substep > 1.0 {
+            return Err(Error::InvalidParameter {
+                name: "min_substep".to_string(),
+                value: min_substep,
+                reason: "must be in (0, 1]".to_string(),
+            });
+        }
+        self.min_substep = min_substep;
+        Ok(self)
+    }
+
+    /// Sets the parameters of the local solver of the backward Euler update
+    ///
+    /// The parameters are also given to the implicit local integrators run by [Model::simulate_path] and
+    /// [Model::local_update] (see [LocalIntegrator::set_local_params]).
+    ///
+    /// Returns an error if the parameters are invalid (see [LocalParams::validate]).
+    pub fn set_local_params(&mut self, params: LocalParams) -> Result<&mut Self, Error> {
+        params.validate()?;
+        self.local_params = params;
+        Ok(self)
+    }
+
+    /// Returns the parameters of the local solver of the backward Euler update
+    pub fn local_params(&self) -> &LocalParams {
+        &self.local_params
+    }
+
+    /// Enables (or disables) the forward-sensitivity tangent of the ODE route in [Model::simulate_path]
+    ///
+    /// If enabled, each ODE update is repeated with [Model::ode_update_with_sensitivity] and the tangent is
+    /// stored in [StepRecord::ctm_ode]. This roughly doubles the cost of the ODE route; the default is false.
+    pub fn set_ode_sensitivity(&mut self, enabled: bool) -> &mut Self {
+        self.ode_sensitivity = enabled;
+        self
+    }
+
+    /// Enables (or disables with None) the solvability analysis of each backward Euler update in [Model::simulate_path]
+    ///
+    /// The updates that are not well posed are reported in [SimulationResult::ill_posed].
+    pub fn set_solvability_check(&mut self, params: Option<SolvabilityParams>) -> Result<&mut Self, Error> {
+        if let Some(p) = &params {
+            p.validate()?;
+        }
+        self.solvability_params = params;
+        Ok(self)
+    }
+
+    /// Analyzes the solvability of the backward Euler update from (x0, y0) with the increment Δx
+    ///
+    /// Uses the parameters given to [Model::set_solvability_check] or the default ones.
+    pub fn analyze_solvability(&self, x0: f64, y0: f64, ddx: f64) -> Result<Solvability, Error> {
+        let params = self.solvability_params.unwrap_or_default();
+        Solvability::analyze(self.actual.as_ref(), x0, y0, ddx, &params)
     }
 
     /// Performs a backward Euler update
     ///
     /// Calculates x_new and y_new from the total strain increment `Δx`
-    pub fn backward_euler_update(&self, x: &mut f64, y: &mut f64, ddx: f64) -> Result<(), StrError> {
+    ///
+    /// Returns the number of iterations, the final residual and the consistent tangent modulus.
+    ///
+    /// If the local Newton iterations do not converge and a minimum substep smaller than one has been
+    /// set (see [Model::set_min_substep]), the increment is split into substeps ΔT (fractions of Δx)
+    /// by recursive halving. With δ = ΔT Δx, the sensitivity s = dy/dΔx is chained across the
+    /// substeps (s = 0 at T = 0 and subscript 1 denotes the end of the substep at T + ΔT):
+    ///
+    /// ```text
+    /// s ← (s + ΔT f1 + δ (T + ΔT) L1) / (1 - δ J1)
+    /// ```
+    ///
+    /// which yields `(f1 + Δx L1) / (1 - Δx J1)` without substeps.
+    pub fn backward_euler_update(&self, x: &mut f64, y: &mut f64, ddx: f64) -> Result<BackwardEulerStats, Error> {
+        let x0 = *x;
+        let (mut t, mut ddt) = (0.0, 1.0);
+        let (mut yt, mut s) = (*y, 0.0);
+        let mut stats = BackwardEulerStats {
+            n_iterations: 0,
+            residual: 0.0,
+            n_substeps: 0,
+            ctm: 0.0,
+        };
+        loop {
+            let last = ddt >= 1.0 - t;
+            if last {
+                ddt = 1.0 - t;
+            }
+            let delta = ddt * ddx;
+            let (mut x1, mut y1) = (x0 + t * ddx, yt);
+            match self.backward_euler_step(&mut x1, &mut y1, delta) {
+                Ok((n_iterations, residual)) => {
+                    let t1 = t + ddt;
+                    let f1 = self.actual.calc_f(x1, y1);
+                    let ll1 = self.actual.calc_ll(x1, y1);
+                    let jj1 = self.actual.calc_jj(x1, y1);
+                    s = (s + ddt * f1 + delta * t1 * ll1) / (1.0 - delta * jj1);
+                    yt = y1;
+                    stats.n_iterations += n_iterations;
+                    stats.residual = residual;
+                    stats.n_substeps += 1;
+                    if last {
+                        break;
+                    }
+                    t = t1;
+                }
+                Err(err @ (Error::LocalNewtonFailure { .. } | Error::NonFinite { .. })) => {
+                    if ddt / 2.0 < self.min_substep {
+                        if self.min_substep >= 1.0 {
+                            return Err(err);
+                        }
+                        return Err(Error::SubstepTooSmall {
+                            x0,
+                            y0: *y,
+                            ddx,
+                            pseudo_time: t,
+                        });
+                    }
+                    ddt /= 2.0;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        *x = x0 + ddx;
+        *y = yt;
+        stats.ctm = s;
+        Ok(stats)
+    }
+
+    /// Performs a single backward Euler step (without substepping)
+    ///
+    /// Returns the number of iterations and the final residual.
+    fn backward_euler_step(&self, x: &mut f64, y: &mut f64, ddx: f64) -> Result<(usize, f64), Error> {
         let x0 = *x;
         let y0 = *y;
         let x1 = x0 + ddx;
         let f_trial = self.actual.calc_f(x1, y0);
         let y_trial = y0 + ddx * f_trial;
+        let (y1, n_iterations, r1) =
+            self.local_params
+                .solve(x0, y0, ddx, "the backward Euler residual", y_trial, |y1| {
+                    let f1 = self.actual.calc_f(x1, y1);
+                    let jj1 = self.actual.calc_jj(x1, y1);
+                    (y1 - y0 - ddx * f1, 1.0 - ddx * jj1)
+                })?;
         *x = x1;
-        *y = y_trial;
-        let mut converged = false;
-        for _ in 0..N_ITERATIONS_MAX {
-            let f1 = self.actual.calc_f(*x, *y);
-            let r1 = *y - y0 - ddx * f1;
-            if f64::abs(r1) < BE_TOLERANCE {
-                converged = true;
-                break;
-            }
-            let jj1 = self.actual.calc_jj(*x, *y);
-            let dy = -r1 / (1.0 - ddx * jj1);
-            *y += dy;
-        }
-        if !converged {
-            return Err("Backward Euler did not converge");
-        }
-        Ok(())
+        *y = y1;
+        Ok((n_iterations, r1))
+    }
+
+    /// Adds a local integrator to be run by [Model::simulate_path] beside backward Euler
+    ///
+    /// The results are reported in [SimulationResult::integrators] in the same order as added.
+    pub fn add_integrator<I: LocalIntegrator + 'static>(&mut self, integrator: I) -> &mut Self {
+        self.integrators.push(Box::new(integrator));
+        self
+    }
+
+    /// Performs an update with a local integrator and returns its consistent tangent modulus
+    ///
+    /// The integrator uses the parameters of the local solver of this model (see [Model::set_local_params]).
+    pub fn local_update(
+        &self,
+        integrator: &mut dyn LocalIntegrator,
+        x: &mut f64,
+        y: &mut f64,
+        ddx: f64,
+    ) -> Result<f64, Error> {
+        integrator.set_local_params(&self.local_params);
+        integrator.update(self.actual.as_ref(), x, y, ddx)
     }
 
     /// Performs an update using the ODE solver
-    pub fn ode_update(&mut self, x: &mut f64, y: &mut f64, ddx: f64) -> Result<(), StrError> {
+    pub fn ode_update(&mut self, x: &mut f64, y: &mut f64, ddx: f64) -> Result<(), Error> {
         let mut yy = Vector::from(&[*y]);
         let mut args = ArgsForODE {
             model: self.actual.clone(),
             x0: *x,
             ddx,
         };
-        self.ode_solver.solve(&mut yy, 0.0, 1.0, None, &mut args, None)?;
+        self.ode_solver
+            .solve(&mut yy, 0.0, 1.0, None, &mut args, None)
+            .map_err(|message| Error::OdeSolverFailure {
+                message,
+                x0: args.x0,
+                y0: *y,
+                ddx,
+            })?;
         *x = args.x0 + ddx;
+        if !yy[0].is_finite() {
+            return Err(Error::NonFinite {
+                what: "the ODE solution",
+                x: *x,
+                y: yy[0],
+            });
+        }
         *y = yy[0];
         Ok(())
     }
 
+    /// Performs an update using the ODE solver and calculates the consistent tangent modulus of the ODE route
+    ///
+    /// Integrates the forward sensitivity (variational) equation together with the update:
+    ///
+    /// ```text
+    /// s = ∂y/∂Δx
+    /// ds/dt = f(x, y) + Δx (L(x, y) t + J(x, y) s)   with   s(0) = 0
+    /// ```
+    ///
+    /// and returns `s(1) = dy1/dx1`. The augmented system has no analytical Jacobian; thus, implicit
+    /// methods compute it numerically.
+    pub fn ode_update_with_sensitivity(&mut self, x: &mut f64, y: &mut f64, ddx: f64) -> Result<f64, Error> {
+        let mut yy = Vector::from(&[*y, 0.0]);
+        let mut args = ArgsForODE {
+            model: self.actual.clone(),
+            x0: *x,
+            ddx,
+        };
+        self.sensitivity_solver
+            .solve(&mut yy, 0.0, 1.0, None, &mut args, None)
+            .map_err(|message| Error::OdeSolverFailure {
+                message,
+                x0: args.x0,
+                y0: *y,
+                ddx,
+            })?;
+        *x = args.x0 + ddx;
+        if !yy[0].is_finite() || !yy[1].is_finite() {
+            return Err(Error::NonFinite {
+                what: "the ODE solution or its sensitivity",
+                x: *x,
+                y: yy[0],
+            });
+        }
+        *y = yy[0];
+        Ok(yy[1])
+    }
+
     /// Returns the continuous modulus f = dy/dx
     pub fn continuous_modulus(&self, x: f64, y: f64) -> f64 {
         self.actual.calc_f(x, y)
     }
 
     /// Calculates the consistent tangent modulus @ the update point (x1, y1)
+    ///
+    /// This is the tangent of a single backward Euler step; see [BackwardEulerStats::ctm] for substeps.
     pub fn consistent_tangent_modulus(&self, x1: f64, y1: f64, ddx: f64) -> f64 {
         let f1 = self.actual.calc_f(x1, y1);
         let ll1 = self.actual.calc_ll(x1, y1);
@@ -96,46 +400,197 @@ impl<'a> Model<'a> {
         (f1 + ddx * ll1) / (1.0 - ddx * jj1)
     }
 
+    /// Sets the finite difference scheme and the step size of the numerical consistent tangent modulus
+    ///
+    /// With `step = None`, the step is chosen automatically from `Δx` (see [DifferenceScheme::auto_step]);
+    /// then, the tolerances of the ODE solver must be given to [Model::set_ode_tolerances]. The default is the forward difference with a step of 1e-5.
+    pub fn set_numerical_tangent(&mut self, scheme: DifferenceScheme, step: Option<f64>) -> Result<&mut Self, Error> {
+        if let Some(h) = step
+            && !(h.is_finite() && h > 0.0)
+        {
+            return Err(Error::InvalidParameter {
+                name: "step".to_string(),
+                value: h,
+                reason: "must be positive".to_string(),
+            });
+        }
+        self.num_ctm_scheme = scheme;
+        self.num_ctm_step = step;
+        Ok(self)
+    }
+
     /// Approximates the consistent tangent modulus @ the update point (x1, y1), given the previous point (x0, y0)
+    ///
+    /// This is a shortcut to [Model::numerical_tangent] returning the value only.
     pub fn numerical_consistent_tangent_modulus(
         &mut self,
         x0: f64,
         y0: f64,
         ddx: f64,
         use_ode_solution: bool,
-    ) -> Result<f64, StrError> {
-        let mut xa = x0;
-        let mut ya = y0;
-        let mut xb = x0;
-        let mut yb = y0;
-        if use_ode_solution {
-            self.ode_update(&mut xa, &mut ya, ddx)?;
-            self.ode_update(&mut xb, &mut yb, ddx + DELTA)?;
-        } else {
-            self.backward_euler_update(&mut xa, &mut ya, ddx)?;
-            self.backward_euler_update(&mut xb, &mut yb, ddx + DELTA)?;
+    ) -> Result<f64, Error> {
+        Ok(self.numerical_tangent(x0, y0, ddx, use_ode_solution)?.value)
+    }
+
+    /// Approximates the consistent tangent modulus dy1/dΔx with finite differences and estimates its error
+    ///
+    /// Repeats the update from (x0, y0) with perturbed increments `Δx ± k h` using the scheme given to
+    /// [Model::set_numerical_tangent]. The automatic step takes the tolerances of the solver of the route as
+    /// noise level, i.e., `abs_tol + rel_tol |y0|` of the local solver for backward Euler (see [LocalParams])
+    /// or of the ODE solver (see [Model::set_ode_tolerances]). An error is returned if the automatic step of the
+    /// ODE route is requested but the tolerances of the ODE solver are unknown.
+    pub fn numerical_tangent(
+        &mut self,
+        x0: f64,
+        y0: f64,
+        ddx: f64,
+        use_ode_solution: bool,
+    ) -> Result<NumericalTangent, Error> {
+        let scheme = self.num_ctm_scheme;
+        let h = match self.num_ctm_step {
+            Some(h) => h,
+            None => {
+                let (abs_tol, rel_tol) = if use_ode_solution {
+                    self.ode_tolerances.ok_or_else(|| {
+                        Error::Unsupported(
+                            "the automatic step of the ODE route requires Model::set_ode_tolerances".to_string(),
+                        )
+                    })?
+                } else {
+                    (self.local_params.abs_tol, self.local_params.rel_tol)
+                };
+                scheme.auto_step(ddx, abs_tol + rel_tol * f64::abs(y0))
+            }
+        };
+        scheme.differentiate(h, |delta| {
+            let (mut x, mut y) = (x0, y0);
+            if use_ode_solution {
+                self.ode_update(&mut x, &mut y, ddx + delta)?;
+            } else {
+                self.backward_euler_update(&mut x, &mut y, ddx + delta)?;
+            }
+            Ok(y)
+        })
+    }
+
+    /// Calculates the consistent tangent modulus of a single backward Euler step by complex-step differentiation
+    ///
+    /// Repeats the update from (x0, y0) in complex arithmetic with the increment `Δx + ih`:
+    ///
+    /// ```text
+    /// r(y) = y - y0 - (Δx + ih) f(x0 + Δx + ih, y)    and    dy1/dx1 = Im(y1) / h
+    /// ```
+    ///
+    /// The Newton iterations start from the real solution and use the real slope `1 - Δx J`, which is
+    /// exact for the imaginary part up to O(h²). Since there is no subtractive cancellation, h = 1e-30
+    /// yields the tangent exact to round-off. Like [Model::consistent_tangent_modulus], this is the
+    /// tangent of a single step (without substepping).
+    ///
+    /// Returns an error if the model does not implement [ModelTrait::calc_f_complex].
+    pub fn complex_step_tangent(&self, x0: f64, y0: f64, ddx: f64) -> Result<f64, Error> {
+        let (mut x1, mut y1) = (x0, y0);
+        self.backward_euler_step(&mut x1, &mut y1, ddx)?;
+        let h = COMPLEX_STEP;
+        let ddx_c = Complex64::new(ddx, h);
+        let x1_c = Complex64::new(x1, h);
+        let mut y = Complex64::new(y1, 0.0);
+        let mut history = Vec::new();
+        for _ in 0..self.local_params.n_iteration_max {
+            let f = self
+                .actual
+                .calc_f_complex(x1_c, y)
+                .ok_or_else(|| Error::Unsupported("the model does not implement calc_f_complex".to_string()))?;
+            let r = y - y0 - ddx_c * f;
+            if !r.re.is_finite() || !r.im.is_finite() {
+                return Err(Error::NonFinite {
+                    what: "the complex backward Euler residual",
+                    x: x1,
+                    y: y.re,
+                });
+            }
+            history.push(r.norm());
+            let dy = r / (1.0 - ddx * self.actual.calc_jj(x1, y.re));
+            y -= dy;
+            let tol = self.local_params.abs_tol + self.local_params.rel_tol * f64::abs(y.re);
+            if f64::abs(r.re) < tol && f64::abs(dy.im) <= f64::EPSILON * (f64::abs(y.im) + h) {
+                return Ok(y.im / h);
+            }
         }
-        Ok((yb - ya) / (xb - xa))
+        Err(Error::LocalNewtonFailure {
+            x0,
+            y0,
+            ddx,
+            residual: history.last().copied().unwrap_or(f64::NAN),
+            history,
+        })
+    }
+
+    /// Runs the Taylor remainder test of the backward Euler tangent [BackwardEulerStats::ctm] (see [TaylorTest])
+    ///
+    /// Uses 8 perturbations starting at `ε0 = max(|Δx|, 0.01) / 10`.
+    pub fn taylor_test_backward_euler(&self, x0: f64, y0: f64, ddx: f64) -> Result<TaylorTest, Error> {
+        let (mut x, mut y) = (x0, y0);
+        let ctm = self.backward_euler_update(&mut x, &mut y, ddx)?.ctm;
+        TaylorTest::run(ddx, ctm, taylor_eps0(ddx), TAYLOR_N, |ddx| {
+            let (mut x, mut y) = (x0, y0);
+            self.backward_euler_update(&mut x, &mut y, ddx)?;
+            Ok(y)
+        })
+    }
+
+    /// Runs the Taylor remainder test of the tangent of the ODE route (see [Model::ode_update_with_sensitivity])
+    ///
+    /// The ODE solver must be configured with tight tolerances; otherwise, its error control dominates the remainders.
+    pub fn taylor_test_ode(&mut self, x0: f64, y0: f64, ddx: f64) -> Result<TaylorTest, Error> {
+        let (mut x, mut y) = (x0, y0);
+        let ctm = self.ode_update_with_sensitivity(&mut x, &mut y, ddx)?;
+        TaylorTest::run(ddx, ctm, taylor_eps0(ddx), TAYLOR_N, |ddx| {
+            let (mut x, mut y) = (x0, y0);
+            self.ode_update_with_sensitivity(&mut x, &mut y, ddx)?;
+            Ok(y)
+        })
+    }
+
+    /// Runs the Taylor remainder test of the tangent of a local integrator (see [LocalIntegrator])
+    ///
+    /// The integrator is cloned for each update; thus, its history (if any) is the same in all updates.
+    pub fn taylor_test_local<I: LocalIntegrator + Clone>(
+        &self,
+        integrator: &I,
+        x0: f64,
+        y0: f64,
+        ddx: f64,
+    ) -> Result<TaylorTest, Error> {
+        let (mut x, mut y) = (x0, y0);
+        let ctm = self.local_update(&mut integrator.clone(), &mut x, &mut y, ddx)?;
+        TaylorTest::run(ddx, ctm, taylor_eps0(ddx), TAYLOR_N, |ddx| {
+            let (mut x, mut y) = (x0, y0);
+            self.local_update(&mut integrator.clone(), &mut x, &mut y, ddx)?;
+            Ok(y)
+        })
     }
 
     /// Performs a simulation of the model
     ///
-    /// Returns `(xx, yy_be, yy_ode, com_list, ctm_list, num_ctm_list, num_ctm_ode_list)` where:
+    /// Marches `nd` steps with a constant increment `Δx` from `(x_ini, y_ini)`.
+    /// This is a shortcut to [Model::simulate_path] with a [Monotonic] protocol.
+    pub fn simulate(&mut self, x_ini: f64, y_ini: f64, ddx: f64, nd: usize) -> Result<SimulationResult, Error> {
+        self.simulate_path(x_ini, y_ini, &Monotonic::new(ddx, nd))
+    }
+
+    /// Performs a simulation of the model along a strain path
     ///
-    /// - `xx` is the vector of x values (strain)
-    /// - `yy_be` is the vector of y values (stress) calculated with backward Euler
-    /// - `yy_ode` is the vector of y values (stress) calculated with the ODE solver
-    /// - `com_list` is the list of continuous moduli
-    /// - `ctm_list` is the list of consistent tangent moduli
-    /// - `num_ctm_list` is the list of numerical consistent tangent moduli
-    /// - `num_ctm_ode_list` is the list of numerical consistent tangent moduli calculated with the ODE solver
-    pub fn simulate(
+    /// Starting from `(x_ini, y_ini)`, applies the increments given by the loading protocol using both the
+    /// backward Euler update and the ODE solver. The first record in the results holds the initial state.
+    /// The tangent of the ODE route is only calculated if enabled with [Model::set_ode_sensitivity].
+    /// The local integrators added with [Model::add_integrator] follow the same increments independently.
+    /// If enabled with [Model::set_solvability_check], the backward Euler updates are analyzed beforehand.
+    pub fn simulate_path(
         &mut self,
         x_ini: f64,
         y_ini: f64,
-        ddx: f64,
-        nd: usize,
-    ) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>), StrError> {
+        protocol: &dyn LoadingProtocol,
+    ) -> Result<SimulationResult, Error> {
         // Initial values
         let mut x_be = x_ini;
         let mut x_ode = x_ini;
@@ -143,49 +598,90 @@ impl<'a> Model<'a> {
         let mut y_ode = y_ini;
 
         // Perform the backward Euler update
-        let mut xx = vec![0.0; nd + 1];
-        let mut yy_be = vec![0.0; nd + 1];
-        let mut yy_ode = vec![0.0; nd + 1];
-        let mut com_list = vec![0.0; nd + 1];
-        let mut ctm_list = vec![0.0; nd + 1];
-        let mut num_ctm_list = vec![0.0; nd + 1];
-        let mut num_ctm_ode_list = vec![0.0; nd + 1];
+        let mut results = SimulationResult {
+            approximate_tangent: self.actual.approximate_derivatives(),
+            ..Default::default()
+        };
         let com = self.continuous_modulus(x_be, y_be);
-        xx[0] = x_be;
-        yy_be[0] = y_be;
-        yy_ode[0] = y_ode;
-        com_list[0] = com;
-        ctm_list[0] = com;
-        num_ctm_list[0] = com;
-        num_ctm_ode_list[0] = com;
-        for k in 1..=nd {
+        results
+            .records
+            .push(StepRecord::initial(x_be, y_be, com, self.ode_sensitivity));
+        let increments = protocol.increments(x_ini);
+        for &ddx in &increments {
             // x is x0 and y is y0
             let x0 = x_be;
             let y0 = y_be;
+            // check whether the backward Euler update is well posed
+            if let Some(params) = &self.solvability_params {
+                let analysis = Solvability::analyze(self.actual.as_ref(), x0, y0, ddx, params)?;
+                if !analysis.is_well_posed() {
+                    results.ill_posed.push((results.records.len(), analysis));
+                }
+            }
             // perform the backward Euler update
-            self.backward_euler_update(&mut x_be, &mut y_be, ddx)?;
+            let stats = self.backward_euler_update(&mut x_be, &mut y_be, ddx)?;
             // perform the ODE update
             self.ode_update(&mut x_ode, &mut y_ode, ddx)?;
+            let ode_stats = *self.ode_solver.stats();
             // x is now x1 and y is now y1
             let x1 = x_be;
             let y1 = y_be;
             // calculate the continuous modulus
             let com = self.continuous_modulus(x1, y1);
             // calculate the consistent tangent modulus
-            let ctm = self.consistent_tangent_modulus(x1, y1, ddx);
-            let num_ctm = self.numerical_consistent_tangent_modulus(x0, y0, ddx, false)?;
-            let num_ctm_ode = self.numerical_consistent_tangent_modulus(x0, y0, ddx, true)?;
+            let ctm = stats.ctm;
+            let num_ctm = self.numerical_tangent(x0, y0, ddx, false)?;
+            let num_ctm_ode = self.numerical_tangent(x0, y0, ddx, true)?;
+            let ctm_ode = if self.ode_sensitivity {
+                let (mut xs, mut ys) = (x0, y0);
+                Some(self.ode_update_with_sensitivity(&mut xs, &mut ys, ddx)?)
+            } else {
+                None
+            };
             // store the results
-            xx[k] = x1;
-            yy_be[k] = y1;
-            yy_ode[k] = y_ode;
-            com_list[k] = com;
-            ctm_list[k] = ctm;
-            num_ctm_list[k] = num_ctm;
-            num_ctm_ode_list[k] = num_ctm_ode;
+            results.records.push(StepRecord {
+                ddx,
+                x: x1,
+                y_be: y1,
+                y_ode,
+                com,
+                ctm,
+                num_ctm: num_ctm.value,
+                num_ctm_error: num_ctm.error,
+                num_ctm_ode: num_ctm_ode.value,
+                num_ctm_ode_error: num_ctm_ode.error,
+                ctm_ode,
+                n_iterations: stats.n_iterations,
+                n_substeps: stats.n_substeps,
+                residual: stats.residual,
+                ode_stats: Some(ode_stats),
+            });
+        }
+
+        // Perform the updates with the local integrators
+        for integrator in self.integrators.iter_mut() {
+            integrator.reset();
+            integrator.set_local_params(&self.local_params);
+            let (mut x, mut y) = (x_ini, y_ini);
+            let mut res = IntegratorResult {
+                name: integrator.name(),
+                yy: vec![y],
+                ctm: vec![com],
+            };
+            for &ddx in &increments {
+                res.ctm
+                    .push(integrator.update(self.actual.as_ref(), &mut x, &mut y, ddx)?);
+                res.yy.push(y);
+            }
+            results.integrators.push(res);
         }
 
         // Return the results
-        Ok((xx, yy_be, yy_ode, com_list, ctm_list, num_ctm_list, num_ctm_ode_list))
+        Ok(results)
     }
 }
+
+/// Returns the first perturbation of the Taylor remainder tests
+fn taylor_eps0(ddx: f64) -> f64 {
+    f64::max(f64::abs(ddx), 0.01) / 10.0
+}
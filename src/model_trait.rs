@@ -1,10 +1,52 @@
+use russell_lab::{Complex64, deriv1_central5};
+
+/// Defines the interface of a stress-strain model written in rate form
+///
+/// ```text
+/// dy
+/// ── = f(x, y)
+/// dx
+/// ```
+///
+/// where x is strain and y is stress. Implement this trait to run a custom model
+/// through [crate::Model] (see [crate::Model::from_model] and [crate::ModelRegistry]).
+///
+/// Only `calc_f` is required. The derivatives L and J default to finite differences computed by
+/// [russell_lab::deriv1_central5] (adaptive step) and the consistent tangent is then flagged as
+/// approximate (see [ModelTrait::approximate_derivatives]). Models implementing exact derivatives
+/// should override `approximate_derivatives` to return false. See also [crate::AutoDiff] for exact
+/// derivatives of models written generically.
 pub trait ModelTrait {
     /// Calculates dy/dx = f(x,y)
     fn calc_f(&self, x: f64, y: f64) -> f64;
 
     /// Calculates L = ∂f/∂x
-    fn calc_ll(&self, x: f64, y: f64) -> f64;
+    ///
+    /// The default implementation uses finite differences (NaN if they fail).
+    fn calc_ll(&self, x: f64, y: f64) -> f64 {
+        deriv1_central5(x, &mut 0, |x, _| Ok(self.calc_f(x, y))).unwrap_or(f64::NAN)
+    }
 
     /// Calculates J = ∂f/∂y
-    fn calc_jj(&self, x: f64, y: f64) -> f64;
+    ///
+    /// The default implementation uses finite differences (NaN if they fail).
+    fn calc_jj(&self, x: f64, y: f64) -> f64 {
+        deriv1_central5(y, &mut 0, |y, _| Ok(self.calc_f(x, y))).unwrap_or(f64::NAN)
+    }
+
+    /// Returns true if L and J are approximations (e.g., the default finite differences)
+    ///
+    /// This flag is reported in [crate::SimulationResult::approximate_tangent]. The default is true
+    /// because the default L and J are approximations; override it if `calc_ll` and `calc_jj` are exact.
+    fn approximate_derivatives(&self) -> bool {
+        true
+    }
+
+    /// Calculates f(x, y) with complex arguments (for complex-step differentiation)
+    ///
+    /// Returns None if the model does not support complex arithmetic (default). Models written
+    /// generically over [crate::Scalar] implement this by calling the generic version of `calc_f`.
+    fn calc_f_complex(&self, _x: Complex64, _y: Complex64) -> Option<Complex64> {
+        None
+    }
 }
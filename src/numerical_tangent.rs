@@ -0,0 +1,143 @@
+use crate::Error;
+
+/// Holds a numerical approximation of the consistent tangent modulus dy1/dx1
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NumericalTangent {
+    /// Approximated value
+    pub value: f64,
+
+    /// Estimated (absolute) truncation error
+    pub error: f64,
+
+    /// Step size of the finite differences
+    pub step: f64,
+}
+
+/// Defines the finite difference scheme of the numerical consistent tangent modulus
+///
+/// See [crate::Model::set_numerical_tangent].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DifferenceScheme {
+    /// First-order forward difference
+    Forward,
+
+    /// Second-order central difference
+    Central,
+
+    /// Fourth-order central difference
+    Central4,
+
+    /// Sixth-order Richardson extrapolation of central differences
+    Richardson,
+}
+
+impl DifferenceScheme {
+    /// Returns the order of accuracy of the scheme
+    pub fn order(&self) -> usize {
+        match self {
+            DifferenceScheme::Forward => 1,
+            DifferenceScheme::Central => 2,
+            DifferenceScheme::Central4 => 4,
+            DifferenceScheme::Richardson => 6,
+        }
+    }
+
+    /// Returns the automatic step size for the increment `Δx`, given the noise level of the update
+    ///
+    /// Balances the truncation error O(hᵖ) with the round-off error O(η/h) where η is the noise level
+    /// (e.g., the tolerance of the local solver), relative to the scale of the increment:
+    ///
+    /// ```text
+    /// h = η^(1/(p+1)) max(|Δx|, √η)
+    /// ```
+    pub fn auto_step(&self, ddx: f64, noise: f64) -> f64 {
+        let eta = f64::max(noise, f64::EPSILON);
+        let p = self.order() as f64;
+        f64::powf(eta, 1.0 / (p + 1.0)) * f64::max(f64::abs(ddx), f64::sqrt(eta))
+    }
+
+    /// Approximates the derivative g'(0) of a function g(δ) with the step size h
+    ///
+    /// The error is estimated by comparing the results with the steps h and 2h:
+    ///
+    /// ```text
+    /// Forward:     D(h) = (g(h) - g(0)) / h                  error ≈ |D(h) - D(2h)|
+    /// Central:     C(h) = (g(h) - g(-h)) / (2h)              error ≈ |C(h) - C(2h)| / 3
+    /// Central4:    D(h) = (4 C(h) - C(2h)) / 3               error ≈ |D(h) - D(2h)| / 15
+    /// Richardson:  R = (16 D(h) - D(2h)) / 15   with D from Central4, error ≈ |R - D(h)|
+    /// ```
+    pub(crate) fn differentiate<F>(&self, h: f64, mut g: F) -> Result<NumericalTangent, Error>
+    where
+        F: FnMut(f64) -> Result<f64, Error>,
+    {
+        let central = |g: &mut F, k: f64| -> Result<f64, Error> { Ok((g(k * h)? - g(-k * h)?) / (2.0 * k * h)) };
+        let (value, error) = match self {
+            DifferenceScheme::Forward => {
+                let g0 = g(0.0)?;
+                let d1 = (g(h)? - g0) / h;
+                let d2 = (g(2.0 * h)? - g0) / (2.0 * h);
+                (d1, f64::abs(d1 - d2))
+            }
+            DifferenceScheme::Central => {
+                let c1 = central(&mut g, 1.0)?;
+                let c2 = central(&mut g, 2.0)?;
+                (c1, f64::abs(c1 - c2) / 3.0)
+            }
+            DifferenceScheme::Central4 | DifferenceScheme::Richardson => {
+                let c1 = central(&mut g, 1.0)?;
+                let c2 = central(&mut g, 2.0)?;
+                let c4 = central(&mut g, 4.0)?;
+                let d1 = (4.0 * c1 - c2) / 3.0;
+                let d2 = (4.0 * c2 - c4) / 3.0;
+                if *self == DifferenceScheme::Central4 {
+                    (d1, f64::abs(d1 - d2) / 15.0)
+                } else {
+                    let r = (16.0 * d1 - d2) / 15.0;
+                    (r, f64::abs(r - d1))
+                }
+            }
+        };
+        Ok(NumericalTangent { value, error, step: h })
+    }
+}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use russell_lab::approx_eq;
+
+    #[test]
+    fn differentiate_works() {
+        // g(δ) = exp(1 + δ) thus g'(0) = e
+        let g = |d: f64| Ok(f64::exp(1.0 + d));
+        let e = std::f64::consts::E;
+        let mut errors = Vec::new();
+        for scheme in [
+            DifferenceScheme::Forward,
+            DifferenceScheme::Central,
+            DifferenceScheme::Central4,
+            DifferenceScheme::Richardson,
+        ] {
+            let res = scheme.differentiate(0.01, g).unwrap();
+            let error = f64::abs(res.value - e);
+            println!(
+                "{:?}: value = {}, error = {:e}, estimate = {:e}",
+                scheme, res.value, error, res.error
+            );
+            assert!(error < 2.0 * res.error || error < 1e-12);
+            errors.push(error);
+        }
+        assert!(errors.is_sorted_by(|a, b| a > b));
+        approx_eq(errors[0], e * 0.01 / 2.0, 1e-4);
+    }
+
+    #[test]
+    fn auto_step_works() {
+        let scheme = DifferenceScheme::Central;
+        approx_eq(scheme.auto_step(0.1, 1e-6), 1e-3, 1e-15);
+        approx_eq(scheme.auto_step(0.0, 1e-6), 1e-5, 1e-15);
+        assert!(DifferenceScheme::Forward.auto_step(0.1, 0.0) < scheme.auto_step(0.1, 0.0));
+    }
+}
@@ -0,0 +1,120 @@
+use crate::Error;
+use std::collections::HashMap;
+
+/// Holds the metadata of a model parameter
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParamInfo {
+    /// Name (key) of the parameter
+    pub name: &'static str,
+
+    /// Short description
+    pub description: &'static str,
+
+    /// Units of the parameter (x is strain and y is stress)
+    pub units: &'static str,
+
+    /// Default value
+    pub default: f64,
+
+    /// Minimum admissible value (inclusive, unless `min_exclusive`)
+    pub min: f64,
+
+    /// Indicates that the minimum is not admissible (e.g., strictly positive values)
+    pub min_exclusive: bool,
+
+    /// Maximum admissible value (inclusive)
+    pub max: f64,
+}
+
+impl ParamInfo {
+    /// Returns true if the value is finite and within the admissible range
+    pub fn admits(&self, value: f64) -> bool {
+        let above_min = if self.min_exclusive {
+            value > self.min
+        } else {
+            value >= self.min
+        };
+        value.is_finite() && above_min && value <= self.max
+    }
+
+    /// Returns an error naming this parameter if the value is not admissible
+    pub fn check(&self, value: f64) -> Result<(), Error> {
+        if self.admits(value) {
+            return Ok(());
+        }
+        let open = if self.min_exclusive { "(" } else { "[" };
+        let reason = if self.min == 0.0 && self.max == f64::INFINITY {
+            if self.min_exclusive {
+                "must be finite and positive".to_string()
+            } else {
+                "must be finite and non-negative".to_string()
+            }
+        } else {
+            format!("must be finite and within {}{}, {}]", open, self.min, self.max)
+        };
+        Err(Error::InvalidParameter {
+            name: self.name.to_string(),
+            value,
+            reason,
+        })
+    }
+}
+
+/// Checks that all keys in the map correspond to known parameters
+pub(crate) fn check_param_keys(model: &str, params: &HashMap<&str, f64>, info: &[ParamInfo]) -> Result<(), Error> {
+    for key in params.keys() {
+        if !info.iter().any(|p| p.name == *key) {
+            return Err(Error::UnknownParameter {
+                model: model.to_string(),
+                name: key.to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Returns the value of a required parameter
+pub(crate) fn get_param(model: &str, params: &HashMap<&str, f64>, name: &str) -> Result<f64, Error> {
+    params.get(name).copied().ok_or_else(|| Error::MissingParameter {
+        model: model.to_string(),
+        name: name.to_string(),
+    })
+}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_handles_exclusive_bounds() {
+        let mut info = ParamInfo {
+            name: "k",
+            description: "test parameter",
+            units: "-",
+            default: 1.0,
+            min: 0.0,
+            min_exclusive: true,
+            max: f64::INFINITY,
+        };
+        assert!(info.admits(1e-300));
+        assert!(!info.admits(0.0));
+        assert_eq!(
+            info.check(0.0).unwrap_err().to_string(),
+            "parameter 'k' = 0 is invalid: must be finite and positive"
+        );
+        info.max = 2.0;
+        assert!(info.admits(2.0));
+        assert_eq!(
+            info.check(0.0).unwrap_err().to_string(),
+            "parameter 'k' = 0 is invalid: must be finite and within (0, 2]"
+        );
+        info.min_exclusive = false;
+        assert!(info.admits(0.0));
+        assert_eq!(
+            info.check(3.0).unwrap_err().to_string(),
+            "parameter 'k' = 3 is invalid: must be finite and within [0, 2]"
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{Dahlquist, DahlquistParams, Error, HardeningSoftening, HardeningSofteningParams, ModelTrait, ModelType};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Defines a function that allocates a model given its parameters
+pub type ModelAllocator = Box<dyn Fn(HashMap<&str, f64>) -> Result<Arc<dyn ModelTrait>, Error>>;
+
+/// Holds a name-based collection of model allocators
+///
+/// The built-in models ([ModelType::Dahlquist] and [ModelType::HardeningSoftening]) are registered
+/// by default; custom models can be added with [ModelRegistry::register].
+pub struct ModelRegistry {
+    allocators: HashMap<String, ModelAllocator>,
+}
+
+impl ModelRegistry {
+    /// Allocates a new instance with the built-in models
+    pub fn new() -> Self {
+        let mut registry = ModelRegistry {
+            allocators: HashMap::new(),
+        };
+        for model_type in ModelType::all() {
+            registry
+                .register(model_type.name(), move |params| allocate_builtin(model_type, params))
+                .unwrap();
+        }
+        registry
+    }
+
+    /// Registers a new model allocator
+    ///
+    /// Returns an error if the name is already registered.
+    pub fn register<F>(&mut self, name: &str, allocator: F) -> Result<(), Error>
+    where
+        F: Fn(HashMap<&str, f64>) -> Result<Arc<dyn ModelTrait>, Error> + 'static,
+    {
+        if self.allocators.contains_key(name) {
+            return Err(Error::DuplicateModel(name.to_string()));
+        }
+        self.allocators.insert(name.to_string(), Box::new(allocator));
+        Ok(())
+    }
+
+    /// Allocates a model given its name and parameters
+    pub fn allocate(&self, name: &str, params: HashMap<&str, f64>) -> Result<Arc<dyn ModelTrait>, Error> {
+        let allocator = self
+            .allocators
+            .get(name)
+            .ok_or_else(|| Error::UnknownModel(name.to_string()))?;
+        allocator(params)
+    }
+
+    /// Returns the (sorted) names of the registered models
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<_> = self.allocators.keys().cloned().collect();
+        names.sort();
+        names
+    }
+}
+
+impl Default for ModelRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Allocates one of the built-in models
+pub(crate) fn allocate_builtin(
+    model_type: ModelType,
+    params: HashMap<&str, f64>,
+) -> Result<Arc<dyn ModelTrait>, Error> {
+    let actual: Arc<dyn ModelTrait> = match model_type {
+        ModelType::Dahlquist => Arc::new(Dahlquist::new(DahlquistParams::from_map(&params)?)?),
+        ModelType::HardeningSoftening => {
+            Arc::new(HardeningSoftening::new(HardeningSofteningParams::from_map(&params)?)?)
+        }
+    };
+    Ok(actual)
+}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Linear {
+        e: f64,
+    }
+
+    impl ModelTrait for Linear {
+        fn calc_f(&self, _x: f64, _y: f64) -> f64 {
+            self.e
+        }
+        fn calc_ll(&self, _x: f64, _y: f64) -> f64 {
+            0.0
+        }
+        fn calc_jj(&self, _x: f64, _y: f64) -> f64 {
+            0.0
+        }
+    }
+
+    #[test]
+    fn new_registers_builtin_models() {
+        let registry = ModelRegistry::new();
+        assert_eq!(registry.names(), &["Dahlquist", "HardeningSoftening"]);
+        let model = registry
+            .allocate("Dahlquist", HashMap::from([("lambda", 2.0)]))
+            .unwrap();
+        assert_eq!(model.calc_f(0.0, 1.0), -2.0);
+        assert_eq!(
+            registry.allocate("Unknown", HashMap::new()).err(),
+            Some(Error::UnknownModel("Unknown".to_string()))
+        );
+    }
+
+    #[test]
+    fn register_works() {
+        let mut registry = ModelRegistry::new();
+        registry
+            .register("Linear", |params| {
+                let e = *params.get("e").ok_or(Error::MissingParameter {
+                    model: "Linear".to_string(),
+                    name: "e".to_string(),
+                })?;
+                Ok(Arc::new(Linear { e }))
+            })
+            .unwrap();
+        assert_eq!(registry.names(), &["Dahlquist", "HardeningSoftening", "Linear"]);
+        let model = registry.allocate("Linear", HashMap::from([("e", 100.0)])).unwrap();
+        assert_eq!(model.calc_f(0.0, 0.0), 100.0);
+        assert_eq!(
+            registry
+                .register("Dahlquist", |_| Err(Error::UnknownModel("Dahlquist".to_string())))
+                .err(),
+            Some(Error::DuplicateModel("Dahlquist".to_string()))
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{Error, LocalIntegrator, ModelTrait};
+
+/// Implements Rosenbrock (linearly implicit) local integrators with their consistent tangents
+///
+/// The stages u_i solve linear equations with J0 = J(x0, y0) and L0 = L(x0, y0); thus, no iterations
+/// are needed (the non-autonomous form is used because f depends on x):
+///
+/// ```text
+/// (1/(h γ) - J0) u_i = f(x0 + α_i h, y0 + Σ_j a_ij u_j) + Σ_j c_ij u_j / h + γ_i h L0   (j < i)
+/// y1 = y0 + Σ_i m_i u_i
+/// ```
+///
+/// where h = Δx. Differentiating the stage equations with respect to h (J0 and L0 do not depend on h)
+/// yields, with subscript i denoting evaluation at the stage point:
+///
+/// ```text
+/// (1/(h γ) - J0) du_i/dh = u_i/(h² γ) + α_i L_i + J_i Σ_j a_ij du_j/dh
+///                        + Σ_j c_ij (du_j/dh / h - u_j / h²) + γ_i L0
+/// dy1/dx1 = Σ_i m_i du_i/dh
+/// ```
+#[derive(Clone, Debug)]
+pub struct Rosenbrock {
+    name: String,
+    order: usize,
+    gamma: f64,
+    a: Vec<Vec<f64>>,
+    c: Vec<Vec<f64>>,
+    m: Vec<f64>,
+    alpha: Vec<f64>,
+    gamma_i: Vec<f64>,
+}
+
+impl Rosenbrock {
+    /// Allocates the 2-stage, second-order, L-stable ROS2 method with γ = 1 + 1/√2
+    pub fn ros2() -> Self {
+        let g = 1.0 + 1.0 / f64::sqrt(2.0);
+        Rosenbrock {
+            name: "ROS2".to_string(),
+            order: 2,
+            gamma: g,
+            a: vec![vec![], vec![1.0 / g]],
+            c: vec![vec![], vec![-2.0 / g]],
+            m: vec![3.0 / (2.0 * g), 1.0 / (2.0 * g)],
+            alpha: vec![0.0, 1.0],
+            gamma_i: vec![g, -g],
+        }
+    }
+
+    /// Allocates the 3-stage, third-order, A-stable ROS3P method (Lang and Verwer)
+    pub fn ros3p() -> Self {
+        Rosenbrock {
+            name: "ROS3P".to_string(),
+            order: 3,
+            gamma: 0.7886751345948129,
+            a: vec![vec![], vec![1.267949192431123], vec![1.267949192431123, 0.0]],
+            c: vec![
+                vec![],
+                vec![-1.607695154586736],
+                vec![-3.464101615137755, -1.732050807568877],
+            ],
+            m: vec![2.0, 0.5773502691896258, 0.4226497308103742],
+            alpha: vec![0.0, 1.0, 1.0],
+            gamma_i: vec![0.7886751345948129, -0.211324865405187, -1.077350269189626],
+        }
+    }
+
+    /// Returns the number of stages
+    pub fn n_stage(&self) -> usize {
+        self.m.len()
+    }
+
+    /// Returns the order of accuracy
+    pub fn order(&self) -> usize {
+        self.order
+    }
+}
+
+impl LocalIntegrator for Rosenbrock {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn update(&mut self, model: &dyn ModelTrait, x: &mut f64, y: &mut f64, ddx: f64) -> Result<f64, Error> {
+        let (x0, y0, h) = (*x, *y, ddx);
+        if h == 0.0 {
+            // the tangent tends to the continuous modulus as Δx → 0
+            return Ok(model.calc_f(x0, y0));
+        }
+        let jj0 = model.calc_jj(x0, y0);
+        let ll0 = model.calc_ll(x0, y0);
+        let mm = 1.0 / (h * self.gamma) - jj0;
+        if mm == 0.0 || !mm.is_finite() {
+            return Err(Error::SingularJacobian(format!(
+                "the Rosenbrock matrix 1/(h γ) - J0 = {} is singular",
+                mm
+            )));
+        }
+        let s = self.n_stage();
+        let mut uu = vec![0.0; s];
+        let mut du = vec![0.0; s];
+        let mut y1 = y0;
+        let mut ctm = 0.0;
+        for i in 0..s {
+            // stage point
+            let xi = x0 + self.alpha[i] * h;
+            let mut yi = y0;
+            let mut dyi = 0.0;
+            let mut sum_c = 0.0;
+            let mut sum_dc = 0.0;
+            for j in 0..i {
+                yi += self.a[i][j] * uu[j];
+                dyi += self.a[i][j] * du[j];
+                sum_c += self.c[i][j] * uu[j] / h;
+                sum_dc += self.c[i][j] * (du[j] / h - uu[j] / (h * h));
+            }
+            let fi = model.calc_f(xi, yi);
+            let lli = model.calc_ll(xi, yi);
+            let jji = model.calc_jj(xi, yi);
+
+            // stage and its derivative with respect to h
+            uu[i] = (fi + sum_c + self.gamma_i[i] * h * ll0) / mm;
+            du[i] =
+                (uu[i] / (h * h * self.gamma) + self.alpha[i] * lli + jji * dyi + sum_dc + self.gamma_i[i] * ll0) / mm;
+            y1 += self.m[i] * uu[i];
+            ctm += self.m[i] * du[i];
+        }
+        if !y1.is_finite() || !ctm.is_finite() {
+            return Err(Error::NonFinite {
+                what: "the Rosenbrock update",
+                x: x0 + h,
+                y: y1,
+            });
+        }
+        *x = x0 + h;
+        *y = y1;
+        Ok(ctm)
+    }
+}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dahlquist, DahlquistParams};
+    use russell_lab::approx_eq;
+
+    #[test]
+    fn update_matches_the_stability_function() {
+        // Dahlquist: y1 = R(z) y0 with z = -λ Δx and R(z) = 1 + z bᵀ (I - z (A + Γ))⁻¹ 1
+        let lambda = 5.0;
+        let model = Dahlquist::new(DahlquistParams::new().with_lambda(lambda)).unwrap();
+        let ddx = 0.1;
+        let z = -lambda * ddx;
+        let g = 1.0 + 1.0 / f64::sqrt(2.0);
+        let v1 = 1.0 / (1.0 - g * z);
+        let v2 = (1.0 + z * (1.0 - 2.0 * g) * v1) / (1.0 - g * z);
+        let mut scheme = Rosenbrock::ros2();
+        let (mut x, mut y) = (0.0, 2.0);
+        scheme.update(&model, &mut x, &mut y, ddx).unwrap();
+        assert_eq!(x, ddx);
+        approx_eq(y, 2.0 * (1.0 + z * (v1 + v2) / 2.0), 1e-14);
+    }
+
+    #[test]
+    fn update_handles_zero_increments() {
+        let model = Dahlquist::new(DahlquistParams::new().with_lambda(5.0)).unwrap();
+        let mut scheme = Rosenbrock::ros3p();
+        assert_eq!(scheme.n_stage(), 3);
+        assert_eq!(scheme.order(), 3);
+        let (mut x, mut y) = (1.0, 2.0);
+        let ctm = scheme.update(&model, &mut x, &mut y, 0.0).unwrap();
+        assert_eq!((x, y), (1.0, 2.0));
+        assert_eq!(ctm, -10.0);
+    }
+}
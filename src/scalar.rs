@@ -0,0 +1,96 @@
+use russell_lab::Complex64;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Defines the scalar type of the model evaluations (real or complex numbers)
+///
+/// Models written generically over `Scalar` can be evaluated with complex arguments, enabling the
+/// complex-step derivative (see [crate::ModelTrait::calc_f_complex]):
+///
+/// ```text
+/// f(x + ih) = f(x) + ih f'(x) + O(h²)   thus   f'(x) = Im(f(x + ih)) / h + O(h²)
+/// ```
+///
+/// Branches (e.g., `max`) must be taken on the real part, see [Scalar::re].
+pub trait Scalar:
+    Copy
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + Add<f64, Output = Self>
+    + Sub<f64, Output = Self>
+    + Mul<f64, Output = Self>
+    + Div<f64, Output = Self>
+{
+    /// Converts a real number
+    fn from_f64(value: f64) -> Self;
+
+    /// Returns the real part
+    fn re(self) -> f64;
+
+    /// Calculates the exponential
+    fn exp(self) -> Self;
+
+    /// Calculates the natural logarithm
+    fn ln(self) -> Self;
+}
+
+impl Scalar for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn re(self) -> f64 {
+        self
+    }
+
+    fn exp(self) -> Self {
+        f64::exp(self)
+    }
+
+    fn ln(self) -> Self {
+        f64::ln(self)
+    }
+}
+
+impl Scalar for Complex64 {
+    fn from_f64(value: f64) -> Self {
+        Complex64::new(value, 0.0)
+    }
+
+    fn re(self) -> f64 {
+        self.re
+    }
+
+    fn exp(self) -> Self {
+        Complex64::exp(self)
+    }
+
+    fn ln(self) -> Self {
+        Complex64::ln(self)
+    }
+}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use russell_lab::approx_eq;
+
+    fn g<T: Scalar>(x: T) -> T {
+        (x * x * 2.0 + 1.0).ln() - (-x).exp() / 3.0
+    }
+
+    #[test]
+    fn complex_step_works() {
+        // g'(x) = 4x / (2x² + 1) + exp(-x) / 3
+        let x = 0.7;
+        let h = 1e-30;
+        let res = g(Complex64::new(x, h));
+        assert_eq!(res.re, g(x));
+        approx_eq(res.im / h, 4.0 * x / (2.0 * x * x + 1.0) + f64::exp(-x) / 3.0, 1e-15);
+        assert_eq!(Complex64::from_f64(2.0).re(), 2.0);
+    }
+}
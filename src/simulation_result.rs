@@ -0,0 +1,258 @@
+use crate::Solvability;
+use russell_ode::Stats;
+
+/// Holds the results of one step of a simulation
+#[derive(Clone, Copy, Debug)]
+pub struct StepRecord {
+    /// Strain increment of the step (zero for the initial state)
+    pub ddx: f64,
+
+    /// Strain at the end of the step
+    pub x: f64,
+
+    /// Stress calculated with backward Euler
+    pub y_be: f64,
+
+    /// Stress calculated with the ODE solver
+    pub y_ode: f64,
+
+    /// Continuous modulus at (x, y_be)
+    pub com: f64,
+
+    /// Consistent tangent modulus (analytical)
+    pub ctm: f64,
+
+    /// Numerical consistent tangent modulus (backward Euler route)
+    pub num_ctm: f64,
+
+    /// Estimated error of the numerical consistent tangent modulus (backward Euler route)
+    pub num_ctm_error: f64,
+
+    /// Numerical consistent tangent modulus (ODE route)
+    pub num_ctm_ode: f64,
+
+    /// Estimated error of the numerical consistent tangent modulus (ODE route)
+    pub num_ctm_ode_error: f64,
+
+    /// Consistent tangent modulus of the ODE route given by the forward sensitivity equation
+    ///
+    /// Only calculated if enabled with [crate::Model::set_ode_sensitivity].
+    pub ctm_ode: Option<f64>,
+
+    /// Number of Newton iterations of the backward Euler update (zero for the initial state)
+    pub n_iterations: usize,
+
+    /// Number of substeps of the backward Euler update (zero for the initial state)
+    pub n_substeps: usize,
+
+    /// Final residual of the backward Euler update (zero for the initial state)
+    pub residual: f64,
+
+    /// Statistics of the ODE solver (None for the initial state)
+    pub ode_stats: Option<Stats>,
+}
+
+impl StepRecord {
+    /// Allocates a record representing the initial state
+    ///
+    /// The tangent of the ODE route is only set (to the continuous modulus) if `ode_sensitivity` is true.
+    pub(crate) fn initial(x: f64, y: f64, com: f64, ode_sensitivity: bool) -> Self {
+        StepRecord {
+            ddx: 0.0,
+            x,
+            y_be: y,
+            y_ode: y,
+            com,
+            ctm: com,
+            num_ctm: com,
+            num_ctm_error: 0.0,
+            num_ctm_ode: com,
+            num_ctm_ode_error: 0.0,
+            ctm_ode: if ode_sensitivity { Some(com) } else { None },
+            n_iterations: 0,
+            n_substeps: 0,
+            residual: 0.0,
+            ode_stats: None,
+        }
+    }
+}
+
+/// Holds the results of a local integrator along a simulation
+///
+/// The first values correspond to the initial state (the tangent is the continuous modulus).
+#[derive(Clone, Debug)]
+pub struct IntegratorResult {
+    /// Name of the integrator (see [crate::LocalIntegrator::name])
+    pub name: String,
+
+    /// Stress at the end of each step
+    pub yy: Vec<f64>,
+
+    /// Consistent tangent modulus of the integrator at the end of each step
+    pub ctm: Vec<f64>,
+}
+
+/// Holds the results of a simulation
+///
+/// The first record corresponds to the initial state.
+#[derive(Clone, Debug, Default)]
+pub struct SimulationResult {
+    /// Records of each step (including the initial state)
+    pub records: Vec<StepRecord>,
+
+    /// Results of the local integrators added to the model (see [crate::Model::add_integrator])
+    pub integrators: Vec<IntegratorResult>,
+
+    /// Index of the record and analysis of each backward Euler update that is not well posed
+    ///
+    /// Only filled if enabled with [crate::Model::set_solvability_check].
+    pub ill_posed: Vec<(usize, Solvability)>,
+
+    /// Indicates that the consistent tangent moduli are approximate because the derivatives of
+    /// the model are (see [crate::ModelTrait::approximate_derivatives])
+    pub approximate_tangent: bool,
+}
+
+impl SimulationResult {
+    /// Returns the number of records (number of steps + 1)
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns true if there are no records
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Extracts a column of values from the records
+    pub fn column<F>(&self, get: F) -> Vec<f64>
+    where
+        F: Fn(&StepRecord) -> f64,
+    {
+        self.records.iter().map(get).collect()
+    }
+
+    /// Returns the strain increments
+    pub fn ddx_list(&self) -> Vec<f64> {
+        self.column(|r| r.ddx)
+    }
+
+    /// Returns the x values (strain)
+    pub fn xx(&self) -> Vec<f64> {
+        self.column(|r| r.x)
+    }
+
+    /// Returns the y values (stress) calculated with backward Euler
+    pub fn yy_be(&self) -> Vec<f64> {
+        self.column(|r| r.y_be)
+    }
+
+    /// Returns the y values (stress) calculated with the ODE solver
+    pub fn yy_ode(&self) -> Vec<f64> {
+        self.column(|r| r.y_ode)
+    }
+
+    /// Returns the continuous moduli
+    pub fn com_list(&self) -> Vec<f64> {
+        self.column(|r| r.com)
+    }
+
+    /// Returns the consistent tangent moduli
+    pub fn ctm_list(&self) -> Vec<f64> {
+        self.column(|r| r.ctm)
+    }
+
+    /// Returns the numerical consistent tangent moduli (backward Euler route)
+    pub fn num_ctm_list(&self) -> Vec<f64> {
+        self.column(|r| r.num_ctm)
+    }
+
+    /// Returns the estimated errors of the numerical consistent tangent moduli (backward Euler route)
+    pub fn num_ctm_error_list(&self) -> Vec<f64> {
+        self.column(|r| r.num_ctm_error)
+    }
+
+    /// Returns the numerical consistent tangent moduli (ODE route)
+    pub fn num_ctm_ode_list(&self) -> Vec<f64> {
+        self.column(|r| r.num_ctm_ode)
+    }
+
+    /// Returns the consistent tangent moduli of the ODE route (forward sensitivity)
+    ///
+    /// The values that have not been calculated (see [StepRecord::ctm_ode]) are NaN.
+    pub fn ctm_ode_list(&self) -> Vec<f64> {
+        self.column(|r| r.ctm_ode.unwrap_or(f64::NAN))
+    }
+
+    /// Returns the number of Newton iterations of the backward Euler update
+    pub fn n_iterations(&self) -> Vec<usize> {
+        self.records.iter().map(|r| r.n_iterations).collect()
+    }
+
+    /// Returns the number of substeps of the backward Euler update
+    pub fn n_substeps(&self) -> Vec<usize> {
+        self.records.iter().map(|r| r.n_substeps).collect()
+    }
+
+    /// Returns the final residuals of the backward Euler update
+    pub fn residuals(&self) -> Vec<f64> {
+        self.column(|r| r.residual)
+    }
+
+    /// Returns the results of the local integrator with the given name
+    pub fn integrator(&self, name: &str) -> Option<&IntegratorResult> {
+        self.integrators.iter().find(|r| r.name == name)
+    }
+}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn columns_work() {
+        let mut res = SimulationResult::default();
+        assert!(res.is_empty());
+        res.records.push(StepRecord::initial(0.0, 1.0, -2.0, true));
+        res.records.push(StepRecord {
+            ddx: 0.1,
+            x: 0.1,
+            y_be: 0.8,
+            y_ode: 0.81,
+            com: -1.6,
+            ctm: -1.3,
+            num_ctm: -1.31,
+            num_ctm_error: 0.01,
+            num_ctm_ode: -1.5,
+            num_ctm_ode_error: 0.02,
+            ctm_ode: Some(-1.49),
+            n_iterations: 2,
+            n_substeps: 1,
+            residual: 1e-12,
+            ode_stats: None,
+        });
+        assert_eq!(res.len(), 2);
+        assert_eq!(res.ddx_list(), &[0.0, 0.1]);
+        assert_eq!(res.xx(), &[0.0, 0.1]);
+        assert_eq!(res.yy_be(), &[1.0, 0.8]);
+        assert_eq!(res.yy_ode(), &[1.0, 0.81]);
+        assert_eq!(res.com_list(), &[-2.0, -1.6]);
+        assert_eq!(res.ctm_list(), &[-2.0, -1.3]);
+        assert_eq!(res.num_ctm_list(), &[-2.0, -1.31]);
+        assert_eq!(res.num_ctm_error_list(), &[0.0, 0.01]);
+        assert_eq!(res.num_ctm_ode_list(), &[-2.0, -1.5]);
+        assert_eq!(res.ctm_ode_list(), &[-2.0, -1.49]);
+        assert_eq!(res.n_iterations(), &[0, 2]);
+        assert_eq!(res.n_substeps(), &[0, 1]);
+        assert_eq!(res.residuals(), &[0.0, 1e-12]);
+        assert!(res.integrator("FE").is_none());
+        res.integrators.push(IntegratorResult {
+            name: "FE".to_string(),
+            yy: vec![1.0, 0.8],
+            ctm: vec![-2.0, -2.0],
+        });
+        assert_eq!(res.integrator("FE").unwrap().yy, &[1.0, 0.8]);
+    }
+}
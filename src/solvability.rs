@@ -0,0 +1,249 @@
+use crate::{Error, ModelTrait};
+
+/// Holds the parameters of the solvability analysis of the backward Euler update
+///
+/// The residual is scanned on `y0 ± span · max(|y0|, |Δx f(x1, y0)|, 1)` (see [Solvability::analyze]).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolvabilityParams {
+    /// Half-width of the scanned interval (relative)
+    pub span: f64,
+
+    /// Number of sub-intervals of the scan
+    pub n_scan: usize,
+}
+
+impl SolvabilityParams {
+    /// Allocates a new instance with default values
+    pub fn new() -> Self {
+        SolvabilityParams {
+            span: 10.0,
+            n_scan: 1000,
+        }
+    }
+
+    /// Validates the parameters
+    pub fn validate(&self) -> Result<(), Error> {
+        if !self.span.is_finite() || self.span <= 0.0 {
+            return Err(Error::InvalidParameter {
+                name: "span".to_string(),
+                value: self.span,
+                reason: "must be positive".to_string(),
+            });
+        }
+        if self.n_scan < 2 {
+            return Err(Error::InvalidParameter {
+                name: "n_scan".to_string(),
+                value: self.n_scan as f64,
+                reason: "must be at least 2".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Default for SolvabilityParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Holds the results of the solvability analysis of the backward Euler update
+///
+/// The residual of the update from (x0, y0) with the increment Δx is:
+///
+/// ```text
+/// r(y) = y - y0 - Δx f(x1, y)    with    dr/dy = 1 - Δx J(x1, y)
+/// ```
+///
+/// If `Δx J(x1, y) < 1` everywhere, r is strictly increasing and has at most one root. Since x1
+/// changes with the increment, the largest admissible increment is found by bisection on the magnitude
+/// of Δx (keeping its sign) with J re-evaluated at `x1 = x0 + Δx`:
+///
+/// ```text
+/// |Δx_max| = sup { |Δx| : max_y Δx J(x0 + Δx, y) < 1 }    (y within the scanned interval)
+/// ```
+///
+/// The bisection assumes that the condition holds for all increments smaller than an admissible
+/// one; `Δx_max` is infinite if no bound is found up to 2⁶⁴ |Δx|.
+///
+/// Roots are found by sign changes on the scan; double roots (touching zero) are not detected.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Solvability {
+    /// Strain before the update
+    pub x0: f64,
+
+    /// Stress before the update
+    pub y0: f64,
+
+    /// Strain increment
+    pub ddx: f64,
+
+    /// Lower bound of the scanned interval
+    pub y_min: f64,
+
+    /// Upper bound of the scanned interval
+    pub y_max: f64,
+
+    /// Roots of the residual within the scanned interval (sorted)
+    pub roots: Vec<f64>,
+
+    /// Largest magnitude of the increment guaranteeing a unique root within the scanned interval
+    pub ddx_max: f64,
+}
+
+impl Solvability {
+    /// Analyzes the backward Euler update from (x0, y0) with the increment Δx
+    pub fn analyze(
+        model: &dyn ModelTrait,
+        x0: f64,
+        y0: f64,
+        ddx: f64,
+        params: &SolvabilityParams,
+    ) -> Result<Self, Error> {
+        params.validate()?;
+        let x1 = x0 + ddx;
+        let r = |y: f64| y - y0 - ddx * model.calc_f(x1, y);
+        let half = params.span * f64::max(f64::max(f64::abs(y0), f64::abs(ddx * model.calc_f(x1, y0))), 1.0);
+        let (y_min, y_max) = (y0 - half, y0 + half);
+
+        // scan
+        let scan = |i: usize| y_min + (y_max - y_min) * (i as f64) / (params.n_scan as f64);
+        let mut roots = Vec::new();
+        let mut prev: Option<(f64, f64)> = None;
+        for i in 0..(params.n_scan + 1) {
+            let y = scan(i);
+            let ry = r(y);
+            if !ry.is_finite() {
+                prev = None;
+                continue;
+            }
+            if ry == 0.0 {
+                roots.push(y);
+            } else if let Some((y_prev, r_prev)) = prev
+                && r_prev != 0.0
+                && r_prev.signum() != ry.signum()
+                && let Some(root) = bisection(&r, y_prev, r_prev, y)
+            {
+                roots.push(root);
+            }
+            prev = Some((y, ry));
+        }
+
+        // largest admissible increment
+        let direction = if ddx < 0.0 { -1.0 } else { 1.0 };
+        let admissible = |size: f64| {
+            let x1 = x0 + direction * size;
+            (0..(params.n_scan + 1)).all(|i| {
+                let jj = model.calc_jj(x1, scan(i));
+                !jj.is_finite() || size * direction * jj < 1.0
+            })
+        };
+        let size = if ddx == 0.0 { 1.0 } else { f64::abs(ddx) };
+        let ddx_max = largest_admissible(admissible, size);
+        Ok(Solvability {
+            x0,
+            y0,
+            ddx,
+            y_min,
+            y_max,
+            roots,
+            ddx_max,
+        })
+    }
+
+    /// Returns true if the residual has exactly one root within the scanned interval
+    pub fn is_well_posed(&self) -> bool {
+        self.roots.len() == 1
+    }
+
+    /// Returns true if the increment satisfies the sufficient condition for uniqueness (|Δx| < |Δx_max|)
+    pub fn is_unique(&self) -> bool {
+        f64::abs(self.ddx) < self.ddx_max
+    }
+}
+
+/// Finds the supremum of the admissible sizes by doubling the given size and then bisecting
+fn largest_admissible<F: Fn(f64) -> bool>(admissible: F, size: f64) -> f64 {
+    let (mut a, mut b) = (0.0, size);
+    let mut n_doubling = 0;
+    while admissible(b) {
+        if n_doubling == 64 {
+            return f64::INFINITY;
+        }
+        (a, b) = (b, 2.0 * b);
+        n_doubling += 1;
+    }
+    for _ in 0..100 {
+        let c = (a + b) / 2.0;
+        if f64::abs(b - a) <= 1e-14 * c {
+            break;
+        }
+        if admissible(c) {
+            a = c;
+        } else {
+            b = c;
+        }
+    }
+    a
+}
+
+/// Refines a root of r within [a, b] given r(a) and a sign change
+fn bisection<F: Fn(f64) -> f64>(r: &F, mut a: f64, mut ra: f64, mut b: f64) -> Option<f64> {
+    for _ in 0..100 {
+        let c = (a + b) / 2.0;
+        let rc = r(c);
+        if !rc.is_finite() {
+            return None;
+        }
+        if rc == 0.0 || f64::abs(b - a) <= 1e-14 * f64::max(f64::abs(c), 1.0) {
+            return Some(c);
+        }
+        if rc.signum() == ra.signum() {
+            (a, ra) = (c, rc);
+        } else {
+            b = c;
+        }
+    }
+    Some((a + b) / 2.0)
+}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dahlquist, DahlquistParams};
+    use russell_lab::approx_eq;
+
+    #[test]
+    fn validate_captures_errors() {
+        let mut params = SolvabilityParams::new();
+        assert_eq!(params.validate(), Ok(()));
+        params.span = 0.0;
+        assert!(params.validate().is_err());
+        params.span = 1.0;
+        params.n_scan = 1;
+        assert!(params.validate().is_err());
+    }
+
+    #[test]
+    fn analyze_works_for_dahlquist() {
+        // linear residual with slope 1 + λΔx: one root y0/(1 + λΔx)
+        let lambda = 5.0;
+        let model = Dahlquist::new(DahlquistParams::new().with_lambda(lambda)).unwrap();
+        let params = SolvabilityParams::new();
+        let ddx = 0.1;
+        let res = Solvability::analyze(&model, 0.0, 2.0, ddx, &params).unwrap();
+        assert_eq!(res.roots.len(), 1);
+        approx_eq(res.roots[0], 2.0 / (1.0 + lambda * ddx), 1e-13);
+        assert_eq!(res.ddx_max, f64::INFINITY);
+        assert!(res.is_well_posed());
+        assert!(res.is_unique());
+
+        // unloading: J = -λ yields Δx_max = 1/λ
+        let res = Solvability::analyze(&model, 0.0, 2.0, -0.3, &params).unwrap();
+        approx_eq(res.ddx_max, 1.0 / lambda, 1e-13);
+        assert!(res.is_well_posed());
+        assert!(!res.is_unique());
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{Error, LocalIntegrator, ModelTrait};
+
+/// Implements explicit substepping with local error control (modified Euler scheme by Sloan)
+///
+/// The increment is divided into substeps ΔT (fractions of Δx, with pseudo-time T from 0 to 1). Each
+/// substep with δ = ΔT Δx computes:
+///
+/// ```text
+/// k1 = δ f(x, y)
+/// k2 = δ f(x + δ, y + k1)
+/// y_new = y + (k1 + k2) / 2
+/// R = |k2 - k1| / (2 max(|y_new|, 1))
+/// ```
+///
+/// The substep is accepted if R ≤ STOL; in any case, the next substep is scaled by
+/// `q = 0.9 √(STOL/R)` limited to [0.1, 1.1] (q ≤ 1 right after a rejection).
+///
+/// The consistent tangent modulus s = dy/dΔx is obtained by chaining the sensitivities of the
+/// accepted substeps (the fractions ΔT are kept fixed) with x = x0 + T Δx:
+///
+/// ```text
+/// dk1 = ΔT f1 + δ (L1 T + J1 s)
+/// dk2 = ΔT f2 + δ (L2 (T + ΔT) + J2 (s + dk1))
+/// s_new = s + (dk1 + dk2) / 2   with   s = 0 at T = 0
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Substepping {
+    /// Tolerance on the relative local error (STOL)
+    pub tolerance: f64,
+
+    /// Minimum substep size (fraction of the increment)
+    pub ddt_min: f64,
+
+    /// Maximum number of substeps (accepted and rejected) per increment
+    pub n_substep_max: usize,
+
+    n_accepted: usize,
+    n_rejected: usize,
+}
+
+impl Substepping {
+    /// Allocates a new instance
+    pub fn new(tolerance: f64) -> Self {
+        Substepping {
+            tolerance,
+            ddt_min: 1e-6,
+            n_substep_max: 10_000,
+            n_accepted: 0,
+            n_rejected: 0,
+        }
+    }
+
+    /// Returns the number of accepted substeps of the last update
+    pub fn n_accepted(&self) -> usize {
+        self.n_accepted
+    }
+
+    /// Returns the number of rejected substeps of the last update
+    pub fn n_rejected(&self) -> usize {
+        self.n_rejected
+    }
+}
+
+impl LocalIntegrator for Substepping {
+    fn name(&self) -> String {
+        format!("Substepping (STOL = {:e})", self.tolerance)
+    }
+
+    fn update(&mut self, model: &dyn ModelTrait, x: &mut f64, y: &mut f64, ddx: f64) -> Result<f64, Error> {
+        if self.tolerance.is_nan() || self.tolerance <= 0.0 {
+            return Err(Error::InvalidParameter {
+                name: "tolerance".to_string(),
+                value: self.tolerance,
+                reason: "must be positive".to_string(),
+            });
+        }
+        let (x0, y0, h) = (*x, *y, ddx);
+        let (mut t, mut ddt) = (0.0, 1.0);
+        let (mut yt, mut s) = (y0, 0.0);
+        let mut rejected = false;
+        self.n_accepted = 0;
+        self.n_rejected = 0;
+        loop {
+            if self.n_accepted + self.n_rejected == self.n_substep_max {
+                return Err(Error::TooManySubsteps {
+                    x0,
+                    y0,
+                    ddx,
+                    pseudo_time: t,
+                    n_substep_max: self.n_substep_max,
+                });
+            }
+            if ddt < self.ddt_min {
+                return Err(Error::SubstepTooSmall {
+                    x0,
+                    y0,
+                    ddx,
+                    pseudo_time: t,
+                });
+            }
+            let last = ddt >= 1.0 - t;
+            if last {
+                ddt = 1.0 - t;
+            }
+
+            // modified Euler substep and error estimate
+            let delta = ddt * h;
+            let xt = x0 + t * h;
+            let f1 = model.calc_f(xt, yt);
+            let k1 = delta * f1;
+            let f2 = model.calc_f(xt + delta, yt + k1);
+            let k2 = delta * f2;
+            let y_new = yt + (k1 + k2) / 2.0;
+            let r = f64::abs(k2 - k1) / (2.0 * f64::max(f64::abs(y_new), 1.0));
+            if !r.is_finite() || r > self.tolerance {
+                let q = if r.is_finite() {
+                    f64::max(0.9 * f64::sqrt(self.tolerance / r), 0.1)
+                } else {
+                    0.1
+                };
+                ddt *= q;
+                rejected = true;
+                self.n_rejected += 1;
+                continue;
+            }
+
+            // sensitivity of the accepted substep
+            let ll1 = model.calc_ll(xt, yt);
+            let jj1 = model.calc_jj(xt, yt);
+            let ll2 = model.calc_ll(xt + delta, yt + k1);
+            let jj2 = model.calc_jj(xt + delta, yt + k1);
+            let dk1 = ddt * f1 + delta * (ll1 * t + jj1 * s);
+            let dk2 = ddt * f2 + delta * (ll2 * (t + ddt) + jj2 * (s + dk1));
+            s += (dk1 + dk2) / 2.0;
+            yt = y_new;
+            self.n_accepted += 1;
+            if last {
+                break;
+            }
+            t += ddt;
+
+            // next substep
+            let mut q = if r > 0.0 {
+                f64::min(0.9 * f64::sqrt(self.tolerance / r), 1.1)
+            } else {
+                1.1
+            };
+            if rejected {
+                q = f64::min(q, 1.0);
+                rejected = false;
+            }
+            ddt *= q;
+        }
+        *x = x0 + h;
+        *y = yt;
+        Ok(s)
+    }
+}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dahlquist, DahlquistParams};
+    use russell_lab::approx_eq;
+
+    #[test]
+    fn update_captures_errors() {
+        let model = Dahlquist::new(DahlquistParams::new().with_lambda(5.0)).unwrap();
+        let (mut x, mut y) = (0.0, 1.0);
+        let mut scheme = Substepping::new(0.0);
+        assert!(scheme.update(&model, &mut x, &mut y, 0.1).is_err());
+        let mut scheme = Substepping::new(1e-8);
+        scheme.ddt_min = 0.5;
+        assert_eq!(
+            scheme.update(&model, &mut x, &mut y, 0.1).err(),
+            Some(Error::SubstepTooSmall {
+                x0: 0.0,
+                y0: 1.0,
+                ddx: 0.1,
+                pseudo_time: 0.0
+            })
+        );
+        let mut scheme = Substepping::new(1e-8);
+        scheme.ddt_min = 1e-12;
+        scheme.n_substep_max = 3;
+        let err = scheme.update(&model, &mut x, &mut y, 0.1).err().unwrap();
+        match err {
+            Error::TooManySubsteps { n_substep_max, .. } => assert_eq!(n_substep_max, 3),
+            _ => panic!("unexpected error: {}", err),
+        }
+        assert_eq!(scheme.n_accepted() + scheme.n_rejected(), 3);
+    }
+
+    #[test]
+    fn update_with_a_single_substep_is_modified_euler() {
+        // Dahlquist: y1 = y0 (1 - z + z²/2) with z = λ Δx
+        let lambda = 5.0;
+        let model = Dahlquist::new(DahlquistParams::new().with_lambda(lambda)).unwrap();
+        let mut scheme = Substepping::new(1.0);
+        let (mut x, mut y) = (0.0, 2.0);
+        let ddx = 0.1;
+        let z = lambda * ddx;
+        let ctm = scheme.update(&model, &mut x, &mut y, ddx).unwrap();
+        assert_eq!(scheme.n_accepted(), 1);
+        assert_eq!(scheme.n_rejected(), 0);
+        approx_eq(y, 2.0 * (1.0 - z + z * z / 2.0), 1e-15);
+        approx_eq(ctm, 2.0 * (-lambda + lambda * z), 1e-14);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::Error;
+
+/// Implements the Taylor remainder test of a consistent tangent modulus
+///
+/// The update y(Δx) is repeated with the perturbed increments `Δx + ε` for a decreasing sequence
+/// `ε_k = ε0 / 2ᵏ` and the remainders are measured:
+///
+/// ```text
+/// R(ε) = |y(Δx + ε) - y(Δx) - ε CTM|
+/// ```
+///
+/// If the tangent is correct, `R = O(ε²)`; otherwise, `R = O(ε)`. The observed orders are:
+///
+/// ```text
+/// p_k = ln(R_k / R_k+1) / ln(ε_k / ε_k+1)
+/// ```
+///
+/// Unlike a point comparison with finite differences, the order does not depend on the size of
+/// the error of the tangent. The remainders must stay above the noise of the update (e.g., the
+/// tolerance of the local solver); thus, tight tolerances are recommended.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaylorTest {
+    /// Strain increment
+    pub ddx: f64,
+
+    /// Consistent tangent modulus being verified
+    pub ctm: f64,
+
+    /// Result of the unperturbed update y(Δx)
+    pub y: f64,
+
+    /// Perturbations ε
+    pub epsilons: Vec<f64>,
+
+    /// Remainders R(ε)
+    pub remainders: Vec<f64>,
+
+    /// Observed orders between consecutive perturbations
+    pub orders: Vec<f64>,
+}
+
+impl TaylorTest {
+    /// Runs the test given the update function y(Δx), the tangent, the first perturbation ε0 and the number of perturbations
+    pub fn run<F>(ddx: f64, ctm: f64, eps0: f64, n: usize, mut update: F) -> Result<Self, Error>
+    where
+        F: FnMut(f64) -> Result<f64, Error>,
+    {
+        if !(eps0.is_finite() && eps0 > 0.0) {
+            return Err(Error::InvalidParameter {
+                name: "eps0".to_string(),
+                value: eps0,
+                reason: "must be positive".to_string(),
+            });
+        }
+        if n < 2 {
+            return Err(Error::InvalidParameter {
+                name: "n".to_string(),
+                value: n as f64,
+                reason: "must be at least 2".to_string(),
+            });
+        }
+        let y = update(ddx)?;
+        let mut epsilons = Vec::with_capacity(n);
+        let mut remainders = Vec::with_capacity(n);
+        let mut eps = eps0;
+        for _ in 0..n {
+            let y_eps = update(ddx + eps)?;
+            epsilons.push(eps);
+            remainders.push(f64::abs(y_eps - y - eps * ctm));
+            eps /= 2.0;
+        }
+        let orders = epsilons
+            .windows(2)
+            .zip(remainders.windows(2))
+            .map(|(e, r)| f64::ln(r[0] / r[1]) / f64::ln(e[0] / e[1]))
+            .collect();
+        Ok(TaylorTest {
+            ddx,
+            ctm,
+            y,
+            epsilons,
+            remainders,
+            orders,
+        })
+    }
+
+    /// Returns the round-off level of the remainders
+    fn round_off(&self) -> f64 {
+        1e3 * f64::EPSILON * f64::max(f64::abs(self.y), 1.0)
+    }
+
+    /// Returns the observed convergence order (NaN if not available)
+    ///
+    /// Computes the median of the orders whose remainders are above the round-off level.
+    /// The order is 2 for a correct tangent and 1 for a wrong one.
+    pub fn observed_order(&self) -> f64 {
+        let floor = self.round_off();
+        let mut orders: Vec<_> = self
+            .orders
+            .iter()
+            .zip(self.remainders.windows(2))
+            .filter(|(p, r)| p.is_finite() && r[1] > floor)
+            .map(|(p, _)| *p)
+            .collect();
+        if orders.is_empty() {
+            return f64::NAN;
+        }
+        orders.sort_by(f64::total_cmp);
+        let m = orders.len() / 2;
+        if orders.len() % 2 == 1 {
+            orders[m]
+        } else {
+            (orders[m - 1] + orders[m]) / 2.0
+        }
+    }
+
+    /// Returns true if the observed order is within `tol` of 2 (or if all remainders are round-off)
+    ///
+    /// Round-off remainders occur when the update is linear in Δx and the tangent is exact.
+    pub fn passed(&self, tol: f64) -> bool {
+        if self.remainders.iter().all(|&r| r <= self.round_off()) {
+            return true;
+        }
+        f64::abs(self.observed_order() - 2.0) <= tol
+    }
+}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use russell_lab::approx_eq;
+
+    #[test]
+    fn run_captures_errors() {
+        let update = |ddx: f64| Ok(ddx);
+        assert!(TaylorTest::run(0.1, 1.0, 0.0, 5, update).is_err());
+        assert!(TaylorTest::run(0.1, 1.0, 0.01, 1, update).is_err());
+    }
+
+    #[test]
+    fn run_works() {
+        // y(Δx) = exp(Δx) thus CTM = exp(Δx)
+        let update = |ddx: f64| Ok(f64::exp(ddx));
+        let ddx = 0.3;
+        let test = TaylorTest::run(ddx, f64::exp(ddx), 0.01, 6, update).unwrap();
+        assert_eq!(test.orders.len(), 5);
+        approx_eq(test.observed_order(), 2.0, 0.01);
+        assert!(test.passed(0.05));
+
+        // a slightly wrong tangent is first order
+        let test = TaylorTest::run(ddx, f64::exp(ddx) * (1.0 + 1e-2), 0.01, 6, update).unwrap();
+        approx_eq(test.observed_order(), 1.0, 0.1);
+        assert!(!test.passed(0.5));
+
+        // linear update with exact tangent
+        let test = TaylorTest::run(ddx, 2.0, 0.5, 4, |ddx| Ok(2.0 * ddx)).unwrap();
+        assert!(test.passed(0.1));
+    }
+}
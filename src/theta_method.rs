@@ -0,0 +1,184 @@
+use crate::{Error, LocalIntegrator, LocalParams, ModelTrait, newton_scalar};
+
+/// Defines where the rate f is evaluated by the θ-method
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ThetaVariant {
+    /// Generalized trapezoidal rule: weighted average of the rates at both ends of the increment
+    ///
+    /// ```text
+    /// y1 = y0 + Δx [(1 - θ) f(x0, y0) + θ f(x1, y1)]
+    /// ```
+    Trapezoidal,
+
+    /// Generalized midpoint rule: rate evaluated at the intermediate state
+    ///
+    /// ```text
+    /// y1 = y0 + Δx f(xθ, yθ)   with   xθ = x0 + θ Δx   and   yθ = (1 - θ) y0 + θ y1
+    /// ```
+    Midpoint,
+}
+
+/// Implements the θ-method (generalized trapezoidal or midpoint rule) with its consistent tangent
+///
+/// θ = 0 yields forward Euler and θ = 1 yields backward Euler (for both variants). θ = 1/2 yields
+/// the trapezoidal (Crank-Nicolson) rule or the implicit midpoint rule. The consistent tangent
+/// moduli are (subscript θ denotes evaluation at (xθ, yθ)):
+///
+/// ```text
+/// Trapezoidal:  dy1/dx1 = [(1 - θ) f0 + θ (f1 + Δx L1)] / (1 - θ Δx J1)
+/// Midpoint:     dy1/dx1 = (fθ + θ Δx Lθ) / (1 - θ Δx Jθ)
+/// ```
+///
+/// The schemes are second-order accurate for θ = 1/2 and first-order accurate otherwise; they are
+/// A-stable for θ ≥ 1/2.
+#[derive(Clone, Copy, Debug)]
+pub struct ThetaMethod {
+    theta: f64,
+    variant: ThetaVariant,
+    local_params: LocalParams,
+}
+
+impl ThetaMethod {
+    /// Allocates a new instance
+    ///
+    /// Returns an error if θ is not in [0, 1].
+    pub fn new(theta: f64, variant: ThetaVariant) -> Result<Self, Error> {
+        if !(0.0..=1.0).contains(&theta) {
+            return Err(Error::InvalidParameter {
+                name: "theta".to_string(),
+                value: theta,
+                reason: "must be in [0, 1]".to_string(),
+            });
+        }
+        Ok(ThetaMethod {
+            theta,
+            variant,
+            local_params: LocalParams::new(),
+        })
+    }
+
+    /// Allocates the forward Euler scheme (θ = 0)
+    pub fn forward_euler() -> Self {
+        ThetaMethod::new(0.0, ThetaVariant::Trapezoidal).unwrap()
+    }
+
+    /// Allocates the trapezoidal (Crank-Nicolson) scheme (θ = 1/2)
+    pub fn crank_nicolson() -> Self {
+        ThetaMethod::new(0.5, ThetaVariant::Trapezoidal).unwrap()
+    }
+
+    /// Allocates the implicit midpoint scheme (θ = 1/2)
+    pub fn midpoint() -> Self {
+        ThetaMethod::new(0.5, ThetaVariant::Midpoint).unwrap()
+    }
+
+    /// Allocates the backward Euler scheme (θ = 1)
+    pub fn backward_euler() -> Self {
+        ThetaMethod::new(1.0, ThetaVariant::Trapezoidal).unwrap()
+    }
+
+    /// Returns θ
+    pub fn theta(&self) -> f64 {
+        self.theta
+    }
+
+    /// Returns the variant
+    pub fn variant(&self) -> ThetaVariant {
+        self.variant
+    }
+
+    /// Returns the parameters of the local solver
+    pub fn local_params(&self) -> &LocalParams {
+        &self.local_params
+    }
+}
+
+impl LocalIntegrator for ThetaMethod {
+    fn name(&self) -> String {
+        format!("θ-method ({:?}, θ = {})", self.variant, self.theta)
+    }
+
+    fn update(&mut self, model: &dyn ModelTrait, x: &mut f64, y: &mut f64, ddx: f64) -> Result<f64, Error> {
+        let (x0, y0) = (*x, *y);
+        let x1 = x0 + ddx;
+        let th = self.theta;
+        let f0 = model.calc_f(x0, y0);
+        let y_trial = y0 + ddx * f0;
+        let ctm = match self.variant {
+            ThetaVariant::Trapezoidal => {
+                // r(y1) = y1 - y0 - Δx [(1 - θ) f0 + θ f(x1, y1)]
+                let (y1, _) = newton_scalar(&self.local_params, x0, y0, ddx, y_trial, |y1| {
+                    let f1 = model.calc_f(x1, y1);
+                    let jj1 = model.calc_jj(x1, y1);
+                    (y1 - y0 - ddx * ((1.0 - th) * f0 + th * f1), 1.0 - th * ddx * jj1)
+                })?;
+                let f1 = model.calc_f(x1, y1);
+                let ll1 = model.calc_ll(x1, y1);
+                let jj1 = model.calc_jj(x1, y1);
+                *y = y1;
+                ((1.0 - th) * f0 + th * (f1 + ddx * ll1)) / (1.0 - th * ddx * jj1)
+            }
+            ThetaVariant::Midpoint => {
+                // r(y1) = y1 - y0 - Δx f(xθ, yθ)
+                let xt = x0 + th * ddx;
+                let (y1, _) = newton_scalar(&self.local_params, x0, y0, ddx, y_trial, |y1| {
+                    let yt = (1.0 - th) * y0 + th * y1;
+                    let ft = model.calc_f(xt, yt);
+                    let jjt = model.calc_jj(xt, yt);
+                    (y1 - y0 - ddx * ft, 1.0 - th * ddx * jjt)
+                })?;
+                let yt = (1.0 - th) * y0 + th * y1;
+                let ft = model.calc_f(xt, yt);
+                let llt = model.calc_ll(xt, yt);
+                let jjt = model.calc_jj(xt, yt);
+                *y = y1;
+                (ft + th * ddx * llt) / (1.0 - th * ddx * jjt)
+            }
+        };
+        *x = x1;
+        Ok(ctm)
+    }
+
+    fn set_local_params(&mut self, params: &LocalParams) {
+        self.local_params = *params;
+    }
+}
+
+// tests /////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dahlquist, DahlquistParams};
+    use russell_lab::approx_eq;
+
+    #[test]
+    fn new_captures_errors() {
+        assert!(ThetaMethod::new(-0.1, ThetaVariant::Trapezoidal).is_err());
+        assert!(ThetaMethod::new(1.1, ThetaVariant::Midpoint).is_err());
+        assert!(ThetaMethod::new(f64::NAN, ThetaVariant::Midpoint).is_err());
+        let scheme = ThetaMethod::midpoint();
+        assert_eq!(scheme.theta(), 0.5);
+        assert_eq!(scheme.variant(), ThetaVariant::Midpoint);
+        assert_eq!(scheme.name(), "θ-method (Midpoint, θ = 0.5)");
+    }
+
+    #[test]
+    fn update_matches_the_amplification_factor() {
+        // Dahlquist: y1 = y0 (1 - (1 - θ) λ Δx) / (1 + θ λ Δx) for both variants
+        let lambda = 5.0;
+        let model = Dahlquist::new(DahlquistParams::new().with_lambda(lambda)).unwrap();
+        let ddx = 0.1;
+        for theta in [0.0, 0.25, 0.5, 1.0] {
+            for variant in [ThetaVariant::Trapezoidal, ThetaVariant::Midpoint] {
+                let mut scheme = ThetaMethod::new(theta, variant).unwrap();
+                let (mut x, mut y) = (0.0, 2.0);
+                let ctm = scheme.update(&model, &mut x, &mut y, ddx).unwrap();
+                let den = 1.0 + theta * lambda * ddx;
+                assert_eq!(x, ddx);
+                approx_eq(y, 2.0 * (1.0 - (1.0 - theta) * lambda * ddx) / den, 1e-14);
+                approx_eq(ctm, -2.0 * lambda / (den * den), 1e-13);
+            }
+        }
+    }
+}
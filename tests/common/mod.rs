@@ -0,0 +1,11 @@
+use ctm_demo::{HardeningSoftening, HardeningSofteningParams, Model};
+use russell_ode::{Method, Params};
+use std::sync::Arc;
+
+/// Allocates a model with the default hardening-softening parameters and the DoPri5 ODE solver
+///
+/// The tests override what they need (e.g., the local or ODE tolerances).
+pub fn allocate_hardening_softening<'a>() -> Model<'a> {
+    let actual = HardeningSoftening::new(HardeningSofteningParams::new()).unwrap();
+    Model::from_model(Arc::new(actual), Params::new(Method::DoPri5)).unwrap()
+}
@@ -0,0 +1,57 @@
+use ctm_demo::{
+    AutoDiff, DifferenceScheme, HardeningSoftening, HardeningSofteningParams, Model, ModelTrait, ModelType,
+};
+use russell_lab::approx_eq;
+use russell_ode::{Method, Params};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Curve fitted from lab data (only the modulus is known)
+///
+/// ```text
+/// f(x, y) = E (1 - y / ys)² + H x
+/// ```
+struct LabCurve;
+
+impl ModelTrait for LabCurve {
+    fn calc_f(&self, x: f64, y: f64) -> f64 {
+        let r = 1.0 - y / 2.5;
+        8.0 * r * r + 0.5 * x
+    }
+}
+
+#[test]
+fn test_approximate_derivatives_defaults() {
+    let model = LabCurve;
+    for (x, y) in [(0.0, 0.0), (0.2, 1.0), (1.0, 2.4), (3.0, 3.0)] {
+        approx_eq(model.calc_ll(x, y), 0.5, 1e-10);
+        approx_eq(model.calc_jj(x, y), -16.0 / 2.5 * (1.0 - y / 2.5), 1e-10);
+    }
+}
+
+#[test]
+fn test_approximate_derivatives_simulate() {
+    // backward Euler and the CTM work with the default derivatives; the results are flagged
+    // (LabCurve only implements calc_f)
+    let mut model = Model::from_model(Arc::new(LabCurve), Params::new(Method::DoPri5)).unwrap();
+    model.set_numerical_tangent(DifferenceScheme::Central, None).unwrap();
+    model.set_ode_tolerances(1e-4, 1e-4).unwrap();
+    let res = model.simulate(0.0, 0.0, 0.05, 10).unwrap();
+    assert!(res.approximate_tangent);
+    for r in &res.records[1..] {
+        approx_eq(r.ctm, r.num_ctm, 1e-5);
+    }
+
+    // models with analytical derivatives are not flagged
+    let mut model = Model::new(
+        ModelType::Dahlquist,
+        HashMap::from([("lambda", 1.0)]),
+        Params::new(Method::DoPri5),
+    )
+    .unwrap();
+    let res = model.simulate(0.0, 1.0, 0.1, 2).unwrap();
+    assert!(!res.approximate_tangent);
+    let params = HardeningSofteningParams::new();
+    assert!(!HardeningSoftening::new(params).unwrap().approximate_derivatives());
+    assert!(!AutoDiff::new(HardeningSoftening::new(params).unwrap()).approximate_derivatives());
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use common::allocate_hardening_softening;
+use ctm_demo::{ArcLength, Bar, JacobianKind, MaterialPointDriver, convergence_rates};
+use plotpy::{Curve, Plot};
+use russell_lab::approx_eq;
+
+const SAVE_FIGURE: bool = false;
+
+#[test]
+fn test_arc_length_material_point() {
+    // Stress control fails beyond the peak stress
+    let model = allocate_hardening_softening();
+    let driver = MaterialPointDriver::new(JacobianKind::Consistent);
+    let (mut x, mut y) = (0.0, 0.0);
+    assert!(driver.stress_step(&model, &mut x, &mut y, 0.6).is_err());
+
+    // Arc-length follows the peak and the softening branch
+    let arc = ArcLength::new(0.02);
+    let steps = arc.run_material_point(&model, 0.0, 0.0, 1.0, 60).unwrap();
+    let (mut x0, mut y0, mut lambda0) = (0.0, 0.0, 0.0);
+    let mut n_limit_point = 0;
+    for (i, step) in steps.iter().enumerate() {
+        // equilibrium: y = λ y_ref with y given by backward Euler
+        let (x1, mut y1) = (step.uu[0], y0);
+        let mut x = x0;
+        model.backward_euler_update(&mut x, &mut y1, x1 - x0).unwrap();
+        approx_eq(y1, step.lambda, 1e-10);
+
+        // constraint: Δx² + ψ² Δλ² = Δl²
+        let (ddx, ddlambda) = (x1 - x0, step.lambda - lambda0);
+        approx_eq(
+            ddx * ddx + ddlambda * ddlambda,
+            step.arc_length * step.arc_length,
+            1e-12,
+        );
+        approx_eq(step.path, 0.02 * ((i + 1) as f64), 1e-14);
+
+        // quadratic convergence (disregarding residuals at the round-off level and the step crossing the limit point)
+        assert!(step.n_iterations <= 5);
+        let residuals: Vec<_> = step.residuals.iter().copied().filter(|r| *r > 1e-13).collect();
+        if let Some(q) = convergence_rates(&residuals).last()
+            && !step.limit_point
+        {
+            assert!(*q > 1.8);
+        }
+
+        // the limit point follows the maximum load factor
+        if step.limit_point {
+            n_limit_point += 1;
+            let lambda_max = steps.iter().fold(0.0, |acc, s| f64::max(acc, s.lambda));
+            assert_eq!(steps[i - 1].lambda, lambda_max);
+        }
+        (x0, y0, lambda0) = (x1, y1, step.lambda);
+    }
+    assert_eq!(n_limit_point, 1);
+    assert!(x0 > 0.4);
+    assert!(lambda0 < 0.1);
+
+    // Plot the results
+    if SAVE_FIGURE {
+        let xx: Vec<_> = steps.iter().map(|s| s.uu[0]).collect();
+        let yy: Vec<_> = steps.iter().map(|s| s.lambda).collect();
+        let mut curve = Curve::new();
+        curve.set_marker_style("o").draw(&xx, &yy);
+        let mut plot = Plot::new();
+        plot.add(&curve)
+            .grid_and_labels("x", "λ")
+            .save("/tmp/consistent_tangent/test_arc_length_material_point.svg")
+            .unwrap();
+    }
+}
+
+#[test]
+fn test_arc_length_bar() {
+    // Fixed-free bar pulled by a reference point load at the tip
+    let (area, length) = (2.0, 3.0);
+    let mut bar = Bar::uniform(allocate_hardening_softening(), length, 3, area, 2).unwrap();
+    bar.set_support(0).unwrap().set_point_load(3, 1.0).unwrap();
+    let mut arc = ArcLength::new(0.05);
+    arc.psi = 0.0;
+    let steps = arc.run_bar(&mut bar, 0.0, 50).unwrap();
+
+    // The stress is uniform and equal to λ P / A
+    let mut n_limit_point = 0;
+    for (i, step) in steps.iter().enumerate() {
+        if step.limit_point {
+            n_limit_point += 1;
+            assert!(steps[i - 1].lambda > step.lambda);
+            assert!(steps[i - 1].lambda > steps[i - 2].lambda);
+        }
+    }
+    let last = steps.last().unwrap();
+    for points in bar.gauss_points() {
+        for p in points {
+            approx_eq(p.y, last.lambda / area, 1e-10);
+            approx_eq(p.x, last.uu[3] / length, 1e-10);
+        }
+    }
+    assert_eq!(n_limit_point, 1);
+    assert!(last.uu[3] / length > 0.4);
+}
+
+#[test]
+fn test_arc_length_captures_errors() {
+    let model = allocate_hardening_softening();
+    assert!(
+        ArcLength::new(0.0)
+            .run_material_point(&model, 0.0, 0.0, 1.0, 1)
+            .is_err()
+    );
+    assert!(
+        ArcLength::new(0.1)
+            .run_material_point(&model, 0.0, 0.0, 0.0, 1)
+            .is_err()
+    );
+}
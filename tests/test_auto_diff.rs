@@ -0,0 +1,78 @@
+use ctm_demo::{
+    AutoDiff, DifferenceScheme, GenericModel, HardeningSoftening, HardeningSofteningParams, LocalParams, Model,
+    ModelTrait, Scalar,
+};
+use russell_lab::approx_eq;
+use russell_ode::{Method, Params};
+use std::sync::Arc;
+
+#[test]
+fn test_auto_diff_hardening_softening() {
+    // the derivatives by automatic differentiation match the hand-derived ones
+    let params = HardeningSofteningParams::new()
+        .with_li(10.0)
+        .with_lr(3.0)
+        .with_y0r(1.0)
+        .with_a(3.0)
+        .with_b(5.0);
+    let model = HardeningSoftening::new(params).unwrap();
+    let auto = AutoDiff::new(HardeningSoftening::new(params).unwrap());
+    for x in [0.0, 0.1, 0.3, 0.7, 1.5, 40.0] {
+        for y in [-1.0, 0.0, 0.5, 1.0, 2.0] {
+            let scale = |v: f64| 1e-13 * f64::max(f64::abs(v), 1.0);
+            let (f, ll, jj) = (model.calc_f(x, y), model.calc_ll(x, y), model.calc_jj(x, y));
+            assert_eq!(auto.calc_f(x, y), f);
+            approx_eq(auto.calc_ll(x, y), ll, scale(ll));
+            approx_eq(auto.calc_jj(x, y), jj, scale(jj));
+        }
+    }
+
+    // at the reference curve (y = yr), the branch below the curve is active in both versions
+    let (x, y) = (0.0, 1.0);
+    approx_eq(auto.calc_jj(x, y), model.calc_jj(x, y), 1e-14);
+    assert!(model.calc_jj(x, y) != 0.0);
+}
+
+/// Prototype of a saturating law written only once (no hand-derived L and J)
+///
+/// ```text
+/// f(x, y) = E exp(-y / s) / (1 + x)
+/// ```
+struct Saturating {
+    ee: f64,
+    s: f64,
+}
+
+impl GenericModel for Saturating {
+    fn eval_f<T: Scalar>(&self, x: T, y: T) -> T {
+        (-y / self.s).exp() * self.ee / (x + 1.0)
+    }
+}
+
+#[test]
+fn test_auto_diff_prototype() {
+    let auto = AutoDiff::new(Saturating { ee: 10.0, s: 2.0 });
+    let (x, y) = (0.3, 0.8);
+    let f = auto.calc_f(x, y);
+    approx_eq(auto.calc_ll(x, y), -f / (1.0 + x), 1e-15);
+    approx_eq(auto.calc_jj(x, y), -f / 2.0, 1e-15);
+
+    // the consistent tangent agrees with the complex-step and numerical tangents
+    let mut model = Model::from_model(Arc::new(auto), Params::new(Method::DoPri5)).unwrap();
+    let mut params = LocalParams::new();
+    params.abs_tol = 1e-14;
+    model.set_local_params(params).unwrap();
+    model.set_numerical_tangent(DifferenceScheme::Central4, None).unwrap();
+    model.set_ode_tolerances(1e-4, 1e-4).unwrap();
+    let ddx = 0.05;
+    let res = model.simulate(0.0, 0.0, ddx, 10).unwrap();
+    for i in 1..res.len() {
+        let (prev, r) = (&res.records[i - 1], &res.records[i]);
+        approx_eq(
+            r.ctm,
+            model.complex_step_tangent(prev.x, prev.y_be, ddx).unwrap(),
+            1e-14,
+        );
+        approx_eq(r.ctm, r.num_ctm, 1e-6);
+    }
+}
@@ -0,0 +1,110 @@
+use ctm_demo::{Error, Model, ModelTrait};
+use russell_lab::approx_eq;
+use russell_ode::{Method, Params};
+use std::sync::Arc;
+
+/// Quadratic model without a backward Euler solution for large increments
+///
+/// ```text
+/// dy/dx = y² + x
+/// ```
+struct Quadratic;
+
+impl ModelTrait for Quadratic {
+    fn calc_f(&self, x: f64, y: f64) -> f64 {
+        y * y + x
+    }
+
+    fn calc_ll(&self, _x: f64, _y: f64) -> f64 {
+        1.0
+    }
+
+    fn calc_jj(&self, _x: f64, y: f64) -> f64 {
+        2.0 * y
+    }
+}
+
+fn allocate_model<'a>(min_substep: f64) -> Model<'a> {
+    let mut model = Model::from_model(Arc::new(Quadratic), Params::new(Method::DoPri5)).unwrap();
+    model.set_min_substep(min_substep).unwrap();
+    model
+}
+
+#[test]
+fn test_backward_euler_substepping_recovers_from_failures() {
+    // Without substepping, the update fails
+    let ddx = 0.4;
+    let (mut x, mut y) = (0.0, 1.0);
+    let model = allocate_model(1.0);
+    assert!(matches!(
+        model.backward_euler_update(&mut x, &mut y, ddx),
+        Err(Error::LocalNewtonFailure { .. })
+    ));
+
+    // With substepping, the update converges
+    let model = allocate_model(1.0 / 64.0);
+    let (mut x, mut y) = (0.0, 1.0);
+    let stats = model.backward_euler_update(&mut x, &mut y, ddx).unwrap();
+    println!(
+        "n_substeps = {}, n_iterations = {}",
+        stats.n_substeps, stats.n_iterations
+    );
+    assert!(stats.n_substeps > 1);
+    approx_eq(x, ddx, 1e-15);
+
+    // The chained tangent matches the central difference of the whole increment
+    let h = 1e-6;
+    let (mut xa, mut ya) = (0.0, 1.0);
+    let (mut xb, mut yb) = (0.0, 1.0);
+    let stats_a = model.backward_euler_update(&mut xa, &mut ya, ddx - h).unwrap();
+    let stats_b = model.backward_euler_update(&mut xb, &mut yb, ddx + h).unwrap();
+    assert_eq!(stats_a.n_substeps, stats.n_substeps);
+    assert_eq!(stats_b.n_substeps, stats.n_substeps);
+    approx_eq(stats.ctm, (yb - ya) / (2.0 * h), 1e-6);
+
+    // The single-step formula does not apply to the whole increment
+    assert!(f64::abs(model.consistent_tangent_modulus(x, y, ddx) - stats.ctm) > 1.0);
+}
+
+#[test]
+fn test_backward_euler_substepping_simulate() {
+    // Small increments do not need substeps; the tangent equals the single-step formula
+    let mut model = allocate_model(1.0 / 64.0);
+    let res = model.simulate(0.0, 0.5, 0.01, 5).unwrap();
+    assert_eq!(res.n_substeps(), &[0, 1, 1, 1, 1, 1]);
+    for r in &res.records[1..] {
+        approx_eq(r.ctm, model.consistent_tangent_modulus(r.x, r.y_be, r.ddx), 1e-15);
+    }
+
+    // Large increments are split and the (forward difference) numerical tangent confirms the chained one
+    let res = model.simulate(0.0, 0.5, 0.3, 3).unwrap();
+    assert!(res.n_substeps().iter().any(|n| *n > 1));
+    for r in &res.records[1..] {
+        approx_eq(r.num_ctm / r.ctm, 1.0, 1e-3);
+    }
+}
+
+#[test]
+fn test_backward_euler_substepping_captures_errors() {
+    let mut model = allocate_model(1.0);
+    assert!(model.set_min_substep(0.0).is_err());
+    assert!(model.set_min_substep(1.5).is_err());
+    assert!(model.set_min_substep(f64::NAN).is_err());
+
+    // the solution blows up before x = 1
+    let model = allocate_model(1.0 / 16.0);
+    let (mut x, mut y) = (0.0, 1.0);
+    match model.backward_euler_update(&mut x, &mut y, 1.0).unwrap_err() {
+        Error::SubstepTooSmall {
+            x0,
+            y0,
+            ddx,
+            pseudo_time,
+        } => {
+            assert_eq!((x0, y0, ddx), (0.0, 1.0, 1.0));
+            assert!(pseudo_time > 0.0 && pseudo_time < 1.0);
+        }
+        err => panic!("unexpected error: {}", err),
+    }
+    assert_eq!((x, y), (0.0, 1.0));
+}
@@ -0,0 +1,136 @@
+use ctm_demo::{Bar, Error, Model, ModelTrait, ModelType, convergence_rates};
+use plotpy::{Curve, Plot};
+use russell_lab::approx_eq;
+use russell_ode::{Method, Params};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const SAVE_FIGURE: bool = false;
+
+/// Linear elastic model with dy/dx = E
+struct LinearElastic {
+    young: f64,
+}
+
+impl ModelTrait for LinearElastic {
+    fn calc_f(&self, _x: f64, _y: f64) -> f64 {
+        self.young
+    }
+
+    fn calc_ll(&self, _x: f64, _y: f64) -> f64 {
+        0.0
+    }
+
+    fn calc_jj(&self, _x: f64, _y: f64) -> f64 {
+        0.0
+    }
+}
+
+#[test]
+fn test_bar_linear_elastic() {
+    // Fixed-free bar with a point load at the tip and a uniform body force
+    let (young, area, length) = (100.0, 0.5, 2.0);
+    let (pp, b) = (3.0, 1.0);
+    let model = Model::from_model(Arc::new(LinearElastic { young }), Params::new(Method::DoPri5)).unwrap();
+    let mut bar = Bar::uniform(model, length, 4, area, 2).unwrap();
+    bar.set_support(0).unwrap().set_point_load(4, pp).unwrap();
+    bar.set_body_force(b);
+    let step = bar.solve_step().unwrap();
+
+    // Linear elements with consistent loads give exact nodal displacements:
+    // u(s) = (P s + b (L s - s²/2)) / (E A)
+    assert_eq!(step.n_iterations, 1);
+    for (i, u) in bar.displacements().iter().enumerate() {
+        let s = length * (i as f64) / 4.0;
+        approx_eq(*u, (pp * s + b * (length * s - s * s / 2.0)) / (young * area), 1e-14);
+    }
+    assert_eq!(step.reactions.len(), 1);
+    assert_eq!(step.reactions[0].0, 0);
+    approx_eq(step.reactions[0].1, -(pp + b * length), 1e-13);
+
+    // Constant stress in the first element equal to the exact stress at its center: y = (P + b (L - s)) / A
+    for p in &bar.gauss_points()[0] {
+        approx_eq(p.y, (pp + b * (length - length / 8.0)) / area, 1e-12);
+        approx_eq(p.ctm, young, 1e-15);
+    }
+}
+
+#[test]
+fn test_bar_displacement_control() {
+    // Allocate the model and perform a single-point simulation
+    let params = HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]);
+    let mut model = Model::new(
+        ModelType::HardeningSoftening,
+        params.clone(),
+        Params::new(Method::DoPri5),
+    )
+    .unwrap();
+    let ddx = 0.02;
+    let nd = 50;
+    let res = model.simulate(0.0, 0.0, ddx, nd).unwrap();
+    let yy = res.yy_be();
+
+    // Stretch a single-element bar by prescribing the displacement of the right end
+    let (area, length) = (2.0, 3.0);
+    let model = Model::new(ModelType::HardeningSoftening, params, Params::new(Method::DoPri5)).unwrap();
+    let mut bar = Bar::uniform(model, length, 1, area, 2).unwrap();
+    bar.set_support(0).unwrap();
+    let mut yy_bar = vec![0.0];
+    for i in 1..nd + 1 {
+        bar.set_displacement(1, length * ddx * (i as f64)).unwrap();
+        let step = bar.solve_step().unwrap();
+        let (node, reaction) = step.reactions[1];
+        assert_eq!(node, 1);
+        approx_eq(step.reactions[0].1, -reaction, 1e-10);
+        yy_bar.push(reaction / area);
+        approx_eq(yy_bar[i], yy[i], 1e-10);
+    }
+
+    // Plot the results
+    if SAVE_FIGURE {
+        let mut curve = Curve::new();
+        curve.draw(&res.xx(), &yy_bar);
+        let mut plot = Plot::new();
+        plot.add(&curve)
+            .grid_and_labels("x", "y")
+            .save("/tmp/consistent_tangent/test_bar_displacement_control.svg")
+            .unwrap();
+    }
+}
+
+#[test]
+fn test_bar_global_newton_converges_quadratically() {
+    // Pull a three-element bar (non-uniform mesh) with a point load before the peak stress
+    let params = HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]);
+    let model = Model::new(ModelType::HardeningSoftening, params, Params::new(Method::DoPri5)).unwrap();
+    let mut bar = Bar::new(model, &[0.0, 1.0, 2.5, 3.0], 1.0, 3).unwrap();
+    bar.set_support(0).unwrap().set_point_load(3, 0.3).unwrap();
+    let step = bar.solve_step().unwrap();
+    println!("iterations = {}, residuals = {:?}", step.n_iterations, step.residuals);
+    let rates = convergence_rates(&step.residuals);
+    assert!(step.n_iterations <= 8);
+    assert!(rates[rates.len() - 2] > 1.8);
+    approx_eq(step.reactions[0].1, -0.3, 1e-10);
+
+    // Uniform stress along the bar
+    for points in bar.gauss_points() {
+        for p in points {
+            approx_eq(p.y, 0.3, 1e-10);
+        }
+    }
+}
+
+#[test]
+fn test_bar_captures_errors() {
+    let model = || Model::from_model(Arc::new(LinearElastic { young: 1.0 }), Params::new(Method::DoPri5)).unwrap();
+    assert!(Bar::new(model(), &[0.0], 1.0, 1).is_err());
+    assert!(Bar::new(model(), &[0.0, 0.0], 1.0, 1).is_err());
+    assert!(Bar::new(model(), &[0.0, 1.0], 0.0, 1).is_err());
+    assert!(Bar::new(model(), &[0.0, 1.0], 1.0, 4).is_err());
+    let mut bar = Bar::new(model(), &[0.0, 1.0], 1.0, 1).unwrap();
+    assert!(bar.set_support(2).is_err());
+
+    // no supports: the stiffness is singular
+    bar.set_point_load(1, 1.0).unwrap();
+    assert!(matches!(bar.solve_step(), Err(Error::SingularJacobian(_))));
+}
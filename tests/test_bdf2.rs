@@ -0,0 +1,107 @@
+use ctm_demo::{Bdf2, Cyclic, Dahlquist, Model, ModelType};
+use plotpy::{Curve, Plot};
+use russell_lab::approx_eq;
+use russell_ode::{Method, Params};
+use std::collections::HashMap;
+
+const SAVE_FIGURE: bool = false;
+
+/// Returns n increments summing up to x_end and growing by the given ratio
+fn growing_increments(x_end: f64, n: usize, ratio: f64) -> Vec<f64> {
+    let weights: Vec<_> = (0..n).map(|i| f64::powi(ratio, i as i32)).collect();
+    let sum: f64 = weights.iter().sum();
+    weights.iter().map(|w| x_end * w / sum).collect()
+}
+
+#[test]
+fn test_bdf2_order_dahlquist() {
+    // Constant and variable increments
+    let lambda = 5.0;
+    let x_end = 1.0;
+    let y_end = Dahlquist::analytical_y(lambda, x_end);
+    for ratio in [1.0, 1.02] {
+        let mut errors = Vec::new();
+        for n in [40, 80] {
+            let mut model = Model::new(
+                ModelType::Dahlquist,
+                HashMap::from([("lambda", lambda)]),
+                Params::new(Method::DoPri5),
+            )
+            .unwrap();
+            model.add_integrator(Bdf2::new());
+            let ratio_n = f64::powf(ratio, 40.0 / (n as f64));
+            let res = model
+                .simulate_path(0.0, 1.0, &growing_increments(x_end, n, ratio_n))
+                .unwrap();
+            approx_eq(*res.xx().last().unwrap(), x_end, 1e-14);
+            let bdf = res.integrator("BDF2").unwrap();
+            errors.push(f64::abs(bdf.yy.last().unwrap() - y_end));
+
+            // the tangent approximates the exact one, -λ y
+            approx_eq(*bdf.ctm.last().unwrap(), -lambda * y_end, 1e-3);
+        }
+        let order = f64::log2(errors[0] / errors[1]);
+        println!("ratio = {}: order = {:.3}", ratio, order);
+        assert!(f64::abs(order - 2.0) < 0.15);
+    }
+}
+
+#[test]
+fn test_bdf2_tangents_match_finite_differences() {
+    // Keep the history fixed by perturbing copies of the integrator
+    let params = HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]);
+    let model = Model::new(ModelType::HardeningSoftening, params, Params::new(Method::DoPri5)).unwrap();
+    let increments = growing_increments(0.6, 15, 1.1);
+    let h = 1e-5;
+    let mut bdf = Bdf2::new();
+    let (mut x, mut y) = (0.0, 0.0);
+    for ddx in increments {
+        let (mut bdf_a, mut xa, mut ya) = (bdf, x, y);
+        let (mut bdf_b, mut xb, mut yb) = (bdf, x, y);
+        model.local_update(&mut bdf_a, &mut xa, &mut ya, ddx - h).unwrap();
+        model.local_update(&mut bdf_b, &mut xb, &mut yb, ddx + h).unwrap();
+        let ctm = model.local_update(&mut bdf, &mut x, &mut y, ddx).unwrap();
+        approx_eq(ctm, (yb - ya) / (2.0 * h), 1e-5);
+    }
+}
+
+#[test]
+fn test_bdf2_cyclic_loading() {
+    // The history is discarded upon reversal; the solution y = exp(-λ x) does not depend on the path
+    let lambda = 2.0;
+    let mut model = Model::new(
+        ModelType::Dahlquist,
+        HashMap::from([("lambda", lambda)]),
+        Params::new(Method::DoPri5),
+    )
+    .unwrap();
+    model.add_integrator(Bdf2::new());
+    let res = model.simulate_path(0.0, 1.0, &Cyclic::new(&[0.5, 1.0], 20)).unwrap();
+    let bdf = &res.integrators[0];
+    let xx = res.xx();
+    let yy_be = res.yy_be();
+    let mut error = 0.0;
+    let mut error_be = 0.0;
+    for i in 0..res.len() {
+        let y = Dahlquist::analytical_y(lambda, xx[i]);
+        error = f64::max(error, f64::abs(bdf.yy[i] - y));
+        error_be = f64::max(error_be, f64::abs(yy_be[i] - y));
+    }
+    println!("error = {:.2e} (BE: {:.2e})", error, error_be);
+    assert!(error < error_be / 5.0);
+
+    // Plot the results
+    if SAVE_FIGURE {
+        let mut curve1 = Curve::new();
+        let mut curve2 = Curve::new();
+        curve1.set_label("backward Euler").draw(&xx, &yy_be);
+        curve2.set_label("BDF2").set_marker_style(".").draw(&xx, &bdf.yy);
+        let mut plot = Plot::new();
+        plot.add(&curve1)
+            .add(&curve2)
+            .grid_and_labels("x", "y")
+            .legend()
+            .save("/tmp/consistent_tangent/test_bdf2_cyclic_loading.svg")
+            .unwrap();
+    }
+}
@@ -0,0 +1,90 @@
+use ctm_demo::{Dahlquist, Error, LocalParams, Model, ModelTrait, ModelType};
+use russell_lab::approx_eq;
+use russell_ode::{Method, Params};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[test]
+fn test_complex_step_dahlquist() {
+    let lambda = 5.0;
+    let mut model = Model::new(
+        ModelType::Dahlquist,
+        HashMap::from([("lambda", lambda)]),
+        Params::new(Method::DoPri5),
+    )
+    .unwrap();
+    let ddx = 0.2;
+    let res = model.simulate(0.0, 1.0, ddx, 5).unwrap();
+    for i in 1..res.len() {
+        let (prev, r) = (&res.records[i - 1], &res.records[i]);
+        let ctm = model.complex_step_tangent(prev.x, prev.y_be, ddx).unwrap();
+        approx_eq(ctm, Dahlquist::analytical_ctm(lambda, r.y_be, ddx), 1e-15);
+    }
+
+    // relative tolerance only
+    let mut params = LocalParams::new();
+    params.abs_tol = 0.0;
+    params.rel_tol = 1e-12;
+    model.set_local_params(params).unwrap();
+    let ctm = model.complex_step_tangent(0.0, 1.0, ddx).unwrap();
+    let y1 = 1.0 / (1.0 + lambda * ddx);
+    approx_eq(ctm, Dahlquist::analytical_ctm(lambda, y1, ddx), 1e-15);
+}
+
+#[test]
+fn test_complex_step_hardening_softening() {
+    let mut model = Model::new(
+        ModelType::HardeningSoftening,
+        HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]),
+        Params::new(Method::DoPri5),
+    )
+    .unwrap();
+
+    // the analytical tangent is evaluated at the approximate root; thus, the residual must be small too
+    let mut params = LocalParams::new();
+    params.abs_tol = 1e-14;
+    model.set_local_params(params).unwrap();
+
+    // coarse increments (see test_hardening_softening_curve_coarse)
+    let ddx = 0.05;
+    let res = model.simulate(0.0, 0.0, ddx, 10).unwrap();
+    for i in 1..res.len() {
+        let (prev, r) = (&res.records[i - 1], &res.records[i]);
+        let ctm = model.complex_step_tangent(prev.x, prev.y_be, ddx).unwrap();
+        println!("x = {:.2}, ctm = {}, complex step = {}", r.x, r.ctm, ctm);
+        approx_eq(ctm, r.ctm, 1e-14 * f64::max(f64::abs(r.ctm), 1.0));
+        approx_eq(
+            ctm,
+            model.consistent_tangent_modulus(r.x, r.y_be, ddx),
+            1e-14 * f64::max(f64::abs(r.ctm), 1.0),
+        );
+    }
+}
+
+/// Model without complex arithmetic
+struct Real;
+
+impl ModelTrait for Real {
+    fn calc_f(&self, _x: f64, y: f64) -> f64 {
+        -y
+    }
+
+    fn calc_ll(&self, _x: f64, _y: f64) -> f64 {
+        0.0
+    }
+
+    fn calc_jj(&self, _x: f64, _y: f64) -> f64 {
+        -1.0
+    }
+}
+
+#[test]
+fn test_complex_step_captures_errors() {
+    let model = Model::from_model(Arc::new(Real), Params::new(Method::DoPri5)).unwrap();
+    assert_eq!(
+        model.complex_step_tangent(0.0, 1.0, 0.1).err(),
+        Some(Error::Unsupported(
+            "the model does not implement calc_f_complex".to_string()
+        ))
+    );
+}
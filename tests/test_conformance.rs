@@ -0,0 +1,137 @@
+use ctm_demo::{
+    Conformance, Dahlquist, DahlquistParams, HardeningSoftening, HardeningSofteningParams, LocalParams, Model,
+    ModelTrait, Sampling,
+};
+use russell_ode::{Method, Params};
+use std::sync::Arc;
+
+/// Allocates a model with the tight local tolerance required by the CTM comparison
+fn allocate_model<'a, M: ModelTrait + 'static>(actual: M) -> Model<'a> {
+    let mut model = Model::from_model(Arc::new(actual), Params::new(Method::DoPri5)).unwrap();
+    let mut params = LocalParams::new();
+    params.abs_tol = 1e-13;
+    model.set_local_params(params).unwrap();
+    model
+}
+
+#[test]
+fn test_conformance_builtin_models() {
+    // hardening-softening below the reference curve
+    let mut model = allocate_model(HardeningSoftening::new(HardeningSofteningParams::new()).unwrap());
+    let kit = Conformance::new((0.0, 1.0), (-1.0, -0.1));
+    let report = kit.check(&model);
+    println!(
+        "L: {:e}, J: {:e}, CTM: {:e}",
+        report.max_error_ll(),
+        report.max_error_jj(),
+        report.max_error_ctm()
+    );
+    assert_eq!(report.n_points, 121);
+    assert_eq!(report.n_ctm, 3 * 121);
+    assert!(report.unsolved.is_empty());
+    assert!(report.passed());
+
+    // the updates follow the configuration of the model (the default local tolerance is too loose)
+    let tight = *model.local_params();
+    model.set_local_params(LocalParams::new()).unwrap();
+    let report = kit.check(&model);
+    println!("CTM with the default local tolerance: {:e}", report.max_error_ctm());
+    assert!(!report.passed());
+    model.set_local_params(tight).unwrap();
+
+    // the worst point of J lies on the kink of max(0, yr - y) at yr(1) ≈ 0
+    let kit = Conformance::new((0.0, 1.0), (-1.0, 0.0));
+    let report = kit.check(&model);
+    assert!(!report.passed());
+    let worst = &report.worst_jj[0];
+    assert_eq!((worst.x, worst.y), (1.0, 0.0));
+    assert!(report.max_error_ctm() < kit.tol_ctm);
+
+    // random sampling
+    let model = allocate_model(Dahlquist::new(DahlquistParams::new().with_lambda(5.0)).unwrap());
+    let mut kit = Conformance::new((0.0, 2.0), (-1.0, 1.0));
+    kit.sampling = Sampling::Random { n: 50, seed: 42 };
+    kit.ddx_list = vec![-0.1, 0.05, 0.5];
+    let report = kit.check(&model);
+    assert_eq!(report.n_points, 50);
+    assert!(report.passed());
+}
+
+/// Model with a wrong J (sign error) and NaN for y ≤ 0
+///
+/// ```text
+/// f(x, y) = -ln(y)
+/// ```
+struct Buggy;
+
+impl ModelTrait for Buggy {
+    fn calc_f(&self, _x: f64, y: f64) -> f64 {
+        -f64::ln(y)
+    }
+
+    fn calc_ll(&self, _x: f64, _y: f64) -> f64 {
+        0.0
+    }
+
+    fn calc_jj(&self, _x: f64, y: f64) -> f64 {
+        1.0 / y // should be -1/y
+    }
+}
+
+/// Model whose backward Euler residual has no root for Δx = 0.1 and y0 > 2.4
+///
+/// ```text
+/// f(x, y) = 1 + y²
+/// ```
+struct Unsolvable;
+
+impl ModelTrait for Unsolvable {
+    fn calc_f(&self, _x: f64, y: f64) -> f64 {
+        1.0 + y * y
+    }
+
+    fn calc_ll(&self, _x: f64, _y: f64) -> f64 {
+        0.0
+    }
+
+    fn calc_jj(&self, _x: f64, y: f64) -> f64 {
+        2.0 * y
+    }
+}
+
+#[test]
+fn test_conformance_fails_if_the_updates_fail() {
+    let mut kit = Conformance::new((0.0, 1.0), (5.0, 10.0));
+    kit.ddx_list = vec![0.1];
+    let model = allocate_model(Unsolvable);
+    let report = kit.check(&model);
+    assert!(report.max_error_ll() <= kit.tol_derivatives);
+    assert!(report.max_error_jj() <= kit.tol_derivatives);
+    assert_eq!(report.n_ctm, 0);
+    assert_eq!(report.unsolved.len(), 121);
+    assert!(!report.passed());
+
+    // L and J only
+    kit.ddx_list.clear();
+    assert!(kit.check(&model).passed());
+}
+
+#[test]
+fn test_conformance_detects_errors() {
+    let model = allocate_model(Buggy);
+    let kit = Conformance::new((0.0, 1.0), (1.0, 3.0));
+    let report = kit.check(&model);
+    assert!(!report.passed());
+    assert_eq!(report.max_error_ll(), 0.0);
+    assert_eq!(report.worst_jj.len(), 5);
+    assert!(report.max_error_jj() > 1.0);
+    assert!(report.max_error_ctm() > 1e-3);
+    let worst = &report.worst_jj[0];
+    assert_eq!((worst.quantity, worst.y), ("J", 1.0));
+
+    // non-finite values
+    let kit = Conformance::new((0.0, 1.0), (-1.0, 1.0));
+    let report = kit.check(&model);
+    assert!(!report.non_finite.is_empty());
+    assert!(report.non_finite.iter().any(|p| p.quantity == "f" && p.y <= 0.0));
+}
@@ -0,0 +1,136 @@
+use ctm_demo::{Error, Model, ModelRegistry, ModelTrait};
+use russell_lab::approx_eq;
+use russell_ode::{Method, Params};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Linear hardening towards a saturation stress
+///
+/// ```text
+/// dy/dx = k (ys - y)
+/// ```
+struct Saturation {
+    k: f64,
+    ys: f64,
+}
+
+impl ModelTrait for Saturation {
+    fn calc_f(&self, _x: f64, y: f64) -> f64 {
+        self.k * (self.ys - y)
+    }
+
+    fn calc_ll(&self, _x: f64, _y: f64) -> f64 {
+        0.0
+    }
+
+    fn calc_jj(&self, _x: f64, _y: f64) -> f64 {
+        -self.k
+    }
+
+    fn approximate_derivatives(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn test_custom_model() {
+    // Allocate the model directly
+    let (k, ys) = (20.0, 2.0);
+    let mut model = Model::from_model(Arc::new(Saturation { k, ys }), Params::new(Method::DoPri5)).unwrap();
+
+    // Perform the simulation
+    let ddx = 0.01;
+    let nd = 20;
+    let res = model.simulate(0.0, 0.0, ddx, nd).unwrap();
+    assert!(!res.approximate_tangent);
+    let (xx, yy, yy_ode) = (res.xx(), res.yy_be(), res.yy_ode());
+    let (ctm_list, num_ctm_list) = (res.ctm_list(), res.num_ctm_list());
+
+    // Check the results
+    for i in 0..nd + 1 {
+        // backward Euler: y1 = (y0 + Δx k ys) / (1 + Δx k)  and  dy1/dx1 = k (ys - y1) / (1 + Δx k)
+        if i > 0 {
+            approx_eq(yy[i], (yy[i - 1] + ddx * k * ys) / (1.0 + ddx * k), 1e-14);
+            approx_eq(ctm_list[i], k * (ys - yy[i]) / (1.0 + ddx * k), 1e-13);
+        }
+        approx_eq(yy_ode[i], ys * (1.0 - f64::exp(-k * xx[i])), 1e-4);
+        approx_eq(ctm_list[i], num_ctm_list[i], 1e-2);
+    }
+}
+
+#[test]
+fn test_custom_model_registry() {
+    // Register the model
+    let mut registry = ModelRegistry::new();
+    registry
+        .register("Saturation", |params| {
+            let get = |name: &str| {
+                params.get(name).copied().ok_or(Error::MissingParameter {
+                    model: "Saturation".to_string(),
+                    name: name.to_string(),
+                })
+            };
+            Ok(Arc::new(Saturation {
+                k: get("k")?,
+                ys: get("ys")?,
+            }))
+        })
+        .unwrap();
+
+    // Allocate the models by name
+    for name in registry.names() {
+        let params = match name.as_str() {
+            "Dahlquist" => HashMap::from([("lambda", 1.0)]),
+            "HardeningSoftening" => HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]),
+            _ => HashMap::from([("k", 20.0), ("ys", 2.0)]),
+        };
+        let actual = registry.allocate(&name, params).unwrap();
+        let model = Model::from_model(actual, Params::new(Method::DoPri5)).unwrap();
+        let (mut x, mut y) = (0.0, 0.0);
+        model.backward_euler_update(&mut x, &mut y, 0.01).unwrap();
+        assert_eq!(x, 0.01);
+    }
+}
+
+/// Quadratic model without a backward Euler solution for large increments
+///
+/// ```text
+/// dy/dx = y²
+/// ```
+struct Quadratic;
+
+impl ModelTrait for Quadratic {
+    fn calc_f(&self, _x: f64, y: f64) -> f64 {
+        y * y
+    }
+
+    fn calc_ll(&self, _x: f64, _y: f64) -> f64 {
+        0.0
+    }
+
+    fn calc_jj(&self, _x: f64, y: f64) -> f64 {
+        2.0 * y
+    }
+}
+
+#[test]
+fn test_custom_model_errors() {
+    // the residual r(y) = y - 1 - y² has no real root
+    let model = Model::from_model(Arc::new(Quadratic), Params::new(Method::DoPri5)).unwrap();
+    let (mut x, mut y) = (0.0, 1.0);
+    match model.backward_euler_update(&mut x, &mut y, 1.0).unwrap_err() {
+        Error::LocalNewtonFailure {
+            x0,
+            y0,
+            ddx,
+            residual,
+            history,
+        } => {
+            assert_eq!((x0, y0, ddx), (0.0, 1.0, 1.0));
+            // the safeguarded solver stops as soon as it stagnates (dr/dy = 0 at the minimum of |r|)
+            assert!(history.len() <= model.local_params().n_iteration_max);
+            assert_eq!(f64::abs(residual), *history.last().unwrap());
+        }
+        err => panic!("unexpected error: {}", err),
+    }
+}
@@ -1,7 +1,7 @@
 use ctm_demo::{Dahlquist, Model, ModelType};
 use plotpy::{Curve, Plot, linspace};
 use russell_lab::approx_eq;
-use russell_ode::Method;
+use russell_ode::{Method, Params};
 use std::collections::HashMap;
 
 const SAVE_FIGURE: bool = false;
@@ -11,7 +11,12 @@ fn test_dahlquist() {
     // Allocate the model
     let lambda = 5.0;
     let method = Method::DoPri5;
-    let mut model = Model::new(ModelType::Dahlquist, HashMap::from([("lambda", lambda)]), method).unwrap();
+    let mut model = Model::new(
+        ModelType::Dahlquist,
+        HashMap::from([("lambda", lambda)]),
+        Params::new(method),
+    )
+    .unwrap();
 
     // Set initial conditions
     let x_ini = 0.0;
@@ -22,7 +27,9 @@ fn test_dahlquist() {
     let nd = 5;
 
     // Perform the backward Euler update
-    let (xx, yy, yy_ode, _, ctm_list, num_ctm_list, num_ctm_ode_list) = model.simulate(x_ini, y_ini, ddx, nd).unwrap();
+    let res = model.simulate(x_ini, y_ini, ddx, nd).unwrap();
+    let (xx, yy, yy_ode) = (res.xx(), res.yy_be(), res.yy_ode());
+    let (ctm_list, num_ctm_list, num_ctm_ode_list) = (res.ctm_list(), res.num_ctm_list(), res.num_ctm_ode_list());
 
     // Generate the plot
     if SAVE_FIGURE {
@@ -134,4 +141,11 @@ fn test_dahlquist() {
     for i in 0..nd + 1 {
         approx_eq(ctm_list[i], num_ctm_list[i], 1e-4);
     }
+
+    // Check the statistics (the model is linear; thus Newton converges in one iteration)
+    assert_eq!(res.n_iterations(), &[0, 1, 1, 1, 1, 1]);
+    for record in &res.records[1..] {
+        assert!(f64::abs(record.residual) < 1e-15);
+        assert!(record.ode_stats.unwrap().n_accepted > 0);
+    }
 }
@@ -0,0 +1,86 @@
+use ctm_demo::{Dahlquist, ExponentialEuler, Model, ModelType};
+use plotpy::{Curve, Plot};
+use russell_lab::approx_eq;
+use russell_ode::{Method, Params};
+use std::collections::HashMap;
+
+const SAVE_FIGURE: bool = false;
+
+#[test]
+fn test_exponential_euler_dahlquist() {
+    // Exact for any increment, including those beyond the stability limit of explicit schemes
+    let lambda = 5.0;
+    let mut model = Model::new(
+        ModelType::Dahlquist,
+        HashMap::from([("lambda", lambda)]),
+        Params::new(Method::DoPri5),
+    )
+    .unwrap();
+    model.add_integrator(ExponentialEuler::new());
+    let res = model.simulate(0.0, 1.0, 0.5, 6).unwrap();
+    let exp = &res.integrators[0];
+    for (i, x) in res.xx().iter().enumerate() {
+        let y = Dahlquist::analytical_y(lambda, *x);
+        approx_eq(exp.yy[i], y, 1e-15);
+        approx_eq(exp.ctm[i], -lambda * y, 1e-14);
+    }
+}
+
+#[test]
+fn test_exponential_euler_hardening_softening() {
+    let params = HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]);
+    let mut ode_params = Params::new(Method::DoPri5);
+    ode_params.set_tolerances(1e-10, 1e-10, None).unwrap();
+    let mut model = Model::new(ModelType::HardeningSoftening, params, ode_params).unwrap();
+    model.add_integrator(ExponentialEuler::new());
+    let (ddx, nd) = (0.02, 40);
+    let res = model.simulate(0.0, 0.0, ddx, nd).unwrap();
+
+    // The tangent matches a central difference of the update
+    let h = 1e-5;
+    let mut exp = ExponentialEuler::new();
+    for i in 1..nd + 1 {
+        let x0 = res.records[i - 1].x;
+        let y0 = res.integrators[0].yy[i - 1];
+        let (mut xa, mut ya) = (x0, y0);
+        let (mut xb, mut yb) = (x0, y0);
+        model.local_update(&mut exp, &mut xa, &mut ya, ddx - h).unwrap();
+        model.local_update(&mut exp, &mut xb, &mut yb, ddx + h).unwrap();
+        approx_eq(res.integrators[0].ctm[i], (yb - ya) / (2.0 * h), 1e-6);
+    }
+
+    // The update is closer to the ODE solution than backward Euler
+    let yy_ode = res.yy_ode();
+    let yy_be = res.yy_be();
+    let yy_exp = &res.integrators[0].yy;
+    let mut error = 0.0;
+    let mut error_be = 0.0;
+    for i in 0..res.len() {
+        error = f64::max(error, f64::abs(yy_exp[i] - yy_ode[i]));
+        error_be = f64::max(error_be, f64::abs(yy_be[i] - yy_ode[i]));
+    }
+    println!("error = {:.2e} (BE: {:.2e})", error, error_be);
+    assert!(error < error_be);
+
+    // Plot the results
+    if SAVE_FIGURE {
+        let xx = res.xx();
+        let mut curve1 = Curve::new();
+        let mut curve2 = Curve::new();
+        let mut curve3 = Curve::new();
+        curve1.set_label("ODE").draw(&xx, &yy_ode);
+        curve2.set_label("backward Euler").draw(&xx, &yy_be);
+        curve3
+            .set_label("exponential Euler")
+            .set_marker_style(".")
+            .draw(&xx, yy_exp);
+        let mut plot = Plot::new();
+        plot.add(&curve1)
+            .add(&curve2)
+            .add(&curve3)
+            .grid_and_labels("x", "y")
+            .legend()
+            .save("/tmp/consistent_tangent/test_exponential_euler.svg")
+            .unwrap();
+    }
+}
@@ -1,7 +1,7 @@
-use ctm_demo::{Model, ModelType};
+use ctm_demo::{DifferenceScheme, Model, ModelType};
 use plotpy::{Curve, Plot};
 use russell_lab::approx_eq;
-use russell_ode::Method;
+use russell_ode::{Method, Params};
 use std::collections::HashMap;
 
 const SAVE_FIGURE: bool = true;
@@ -29,13 +29,14 @@ fn run_test(name: &str, first: usize, x_ini: f64, y_ini: f64, ddx: f64, nd: usiz
     let mut model = Model::new(
         ModelType::HardeningSoftening,
         HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]),
-        method,
+        Params::new(method),
     )
     .unwrap();
 
     // Perform the backward Euler update
-    let (xx, yy, yy_ode, com_list, ctm_list, num_ctm_list, num_ctm_ode_list) =
-        model.simulate(x_ini, y_ini, ddx, nd).unwrap();
+    let res = model.simulate(x_ini, y_ini, ddx, nd).unwrap();
+    let (xx, yy, yy_ode, com_list) = (res.xx(), res.yy_be(), res.yy_ode(), res.com_list());
+    let (ctm_list, num_ctm_list, num_ctm_ode_list) = (res.ctm_list(), res.num_ctm_list(), res.num_ctm_ode_list());
 
     // Generate the plot
     if SAVE_FIGURE {
@@ -156,9 +157,11 @@ fn test_hardening_softening_curve_coarse() {
     let mut model = Model::new(
         ModelType::HardeningSoftening,
         HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]),
-        method,
+        Params::new(method),
     )
     .unwrap();
+    model.set_numerical_tangent(DifferenceScheme::Central, None).unwrap();
+    model.set_ode_tolerances(1e-4, 1e-4).unwrap();
 
     // Set initial conditions
     let x_ini = 0.0;
@@ -169,8 +172,9 @@ fn test_hardening_softening_curve_coarse() {
     let nd = 10;
 
     // Perform the backward Euler update
-    let (xx, yy, yy_ode, com_list, ctm_list, num_ctm_list, num_ctm_ode_list) =
-        model.simulate(x_ini, y_ini, ddx, nd).unwrap();
+    let res = model.simulate(x_ini, y_ini, ddx, nd).unwrap();
+    let (xx, yy, yy_ode, com_list) = (res.xx(), res.yy_be(), res.yy_ode(), res.com_list());
+    let (ctm_list, num_ctm_list, num_ctm_ode_list) = (res.ctm_list(), res.num_ctm_list(), res.num_ctm_ode_list());
 
     // Generate the plot
     if SAVE_FIGURE {
@@ -236,7 +240,6 @@ fn test_hardening_softening_curve_coarse() {
     // Compare the consistent tangent moduli
     for i in 0..nd + 1 {
         // println!("i = {}, x = {}, ctm = {}, num_ctm = {}", i, xx[i], ctm_list[i], num_ctm_list[i]);
-        let tol = if i < 6 { 0.001 } else { 0.4 };
-        approx_eq(ctm_list[i], num_ctm_list[i], tol);
+        approx_eq(ctm_list[i], num_ctm_list[i], 1e-5);
     }
 }
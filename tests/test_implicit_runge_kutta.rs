@@ -0,0 +1,134 @@
+use ctm_demo::{Dahlquist, ImplicitRungeKutta, LocalIntegrator, Model, ModelType};
+use plotpy::{Curve, Plot};
+use russell_lab::approx_eq;
+use russell_ode::{Method, Params};
+use std::collections::HashMap;
+
+const SAVE_FIGURE: bool = false;
+
+fn all_schemes() -> Vec<ImplicitRungeKutta> {
+    vec![
+        ImplicitRungeKutta::sdirk2(),
+        ImplicitRungeKutta::sdirk3(),
+        ImplicitRungeKutta::radau_iia2(),
+    ]
+}
+
+#[test]
+fn test_implicit_runge_kutta_order_dahlquist() {
+    let lambda = 5.0;
+    let x_end = 1.0;
+    for scheme in all_schemes() {
+        let mut errors = Vec::new();
+        let mut ctm_errors = Vec::new();
+        for nd in [20, 40] {
+            let mut model = Model::new(
+                ModelType::Dahlquist,
+                HashMap::from([("lambda", lambda)]),
+                Params::new(Method::DoPri5),
+            )
+            .unwrap();
+            model.add_integrator(scheme.clone());
+            let res = model.simulate(0.0, 1.0, x_end / (nd as f64), nd).unwrap();
+            let integrator = &res.integrators[0];
+            let y_end = *integrator.yy.last().unwrap();
+            errors.push(f64::abs(y_end - Dahlquist::analytical_y(lambda, x_end)));
+
+            // the exact update has the tangent -λ y1
+            let ctm_end = *integrator.ctm.last().unwrap();
+            ctm_errors.push(f64::abs(ctm_end + lambda * Dahlquist::analytical_y(lambda, x_end)));
+        }
+        let order = f64::log2(errors[0] / errors[1]);
+        let order_ctm = f64::log2(ctm_errors[0] / ctm_errors[1]);
+        println!(
+            "{:>10}: order = {:.3}, order (CTM) = {:.3}",
+            scheme.name(),
+            order,
+            order_ctm
+        );
+        assert!(f64::abs(order - scheme.order() as f64) < 0.15);
+        assert!(order_ctm > scheme.order() as f64 - 0.15);
+    }
+}
+
+#[test]
+fn test_implicit_runge_kutta_tangents_match_finite_differences() {
+    let params = HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]);
+    let mut model = Model::new(ModelType::HardeningSoftening, params, Params::new(Method::DoPri5)).unwrap();
+    let (ddx, nd) = (0.05, 12);
+    let res = model.simulate(0.0, 0.0, ddx, nd).unwrap();
+    let h = 1e-5;
+    for mut scheme in all_schemes() {
+        for i in 1..nd + 1 {
+            let (x0, y0) = (res.records[i - 1].x, res.records[i - 1].y_be);
+            let (mut x, mut y) = (x0, y0);
+            let ctm = model.local_update(&mut scheme, &mut x, &mut y, ddx).unwrap();
+            let (mut xa, mut ya) = (x0, y0);
+            let (mut xb, mut yb) = (x0, y0);
+            model.local_update(&mut scheme, &mut xa, &mut ya, ddx - h).unwrap();
+            model.local_update(&mut scheme, &mut xb, &mut yb, ddx + h).unwrap();
+            approx_eq(ctm, (yb - ya) / (2.0 * h), 1e-5);
+        }
+    }
+
+    // The one-stage tableau is backward Euler
+    let mut bw_euler = ImplicitRungeKutta::new("BwEuler", 1, &[vec![1.0]], &[1.0], &[1.0]).unwrap();
+    for i in 1..nd + 1 {
+        let (mut x, mut y) = (res.records[i - 1].x, res.records[i - 1].y_be);
+        let ctm = model.local_update(&mut bw_euler, &mut x, &mut y, ddx).unwrap();
+        approx_eq(y, res.records[i].y_be, 1e-8);
+        approx_eq(ctm, res.records[i].ctm, 1e-6);
+    }
+}
+
+#[test]
+fn test_implicit_runge_kutta_hardening_softening() {
+    let params = HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]);
+    let mut ode_params = Params::new(Method::DoPri5);
+    ode_params.set_tolerances(1e-10, 1e-10, None).unwrap();
+    let mut model = Model::new(ModelType::HardeningSoftening, params, ode_params).unwrap();
+    model.set_ode_sensitivity(true);
+    for scheme in all_schemes() {
+        model.add_integrator(scheme);
+    }
+    let res = model.simulate(0.0, 0.0, 0.02, 40).unwrap();
+
+    // The higher-order schemes are closer to the ODE solution and its tangent than backward Euler
+    // (the tangent errors are dominated by the increments crossing the kink at the peak)
+    let max_error = |yy: &[f64], zz: &[f64]| {
+        yy.iter()
+            .zip(zz)
+            .fold(0.0, |acc, (y, z)| f64::max(acc, f64::abs(y - z)))
+    };
+    let (yy_ode, ctm_ode) = (res.yy_ode(), res.ctm_ode_list());
+    let error_be = max_error(&res.yy_be(), &yy_ode);
+    let error_ctm_be = max_error(&res.ctm_list(), &ctm_ode);
+    for integrator in &res.integrators {
+        let error = max_error(&integrator.yy, &yy_ode);
+        let error_ctm = max_error(&integrator.ctm, &ctm_ode);
+        println!(
+            "{:>10}: error = {:.2e} (BE: {:.2e}), CTM error = {:.2e} (BE: {:.2e})",
+            integrator.name, error, error_be, error_ctm, error_ctm_be
+        );
+        assert!(error < error_be / 10.0);
+        assert!(error_ctm < error_ctm_be);
+    }
+
+    // Plot the results
+    if SAVE_FIGURE {
+        let xx = res.xx();
+        let mut curve = Curve::new();
+        curve.set_label("backward Euler").draw(&xx, &res.ctm_list());
+        let mut plot = Plot::new();
+        plot.add(&curve);
+        for integrator in &res.integrators {
+            let mut curve = Curve::new();
+            curve.set_label(&integrator.name).draw(&xx, &integrator.ctm);
+            plot.add(&curve);
+        }
+        plot.grid_and_labels("x", "CTM")
+            .legend()
+            .save("/tmp/consistent_tangent/test_implicit_runge_kutta.svg")
+            .unwrap();
+    }
+}
@@ -0,0 +1,87 @@
+use ctm_demo::{Cyclic, Hold, LoadingProtocol, Model, ModelType, Monotonic, PiecewiseLinear, Sequence};
+use plotpy::{Curve, Plot};
+use russell_lab::approx_eq;
+use russell_ode::{Method, Params};
+use std::collections::HashMap;
+
+const SAVE_FIGURE: bool = false;
+
+#[test]
+fn test_loading_protocol_dahlquist() {
+    // Allocate the model
+    let lambda = 5.0;
+    let mut model = Model::new(
+        ModelType::Dahlquist,
+        HashMap::from([("lambda", lambda)]),
+        Params::new(Method::DoPri5),
+    )
+    .unwrap();
+
+    // Variable increments with a hold step and a reversal
+    let protocol = vec![0.1, 0.05, 0.0, 0.2, -0.1];
+    let res = model.simulate_path(0.0, 1.0, &protocol).unwrap();
+    assert_eq!(res.len(), protocol.len() + 1);
+    assert_eq!(res.ddx_list()[1..], protocol);
+
+    // Check against y1 = y0 / (1 + λ Δx)  and  D = -λ y1 / (1 + λ Δx)
+    let mut y = 1.0;
+    let mut x = 0.0;
+    for (i, ddx) in protocol.iter().enumerate() {
+        x += ddx;
+        y /= 1.0 + lambda * ddx;
+        let record = &res.records[i + 1];
+        approx_eq(record.x, x, 1e-15);
+        approx_eq(record.y_be, y, 1e-15);
+        approx_eq(record.ctm, -lambda * y / (1.0 + lambda * ddx), 1e-14);
+        approx_eq(record.y_ode, f64::exp(-lambda * x), 1e-4);
+    }
+}
+
+#[test]
+fn test_loading_protocol_hardening_softening() {
+    // Allocate the model
+    let params = HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]);
+    let mut model = Model::new(ModelType::HardeningSoftening, params, Params::new(Method::DoPri5)).unwrap();
+
+    // Ramp to the peak, hold, unload, then two cycles from the table
+    let table = "# x_target n_steps\n 0.15 5\n -0.05 10\n 0.2 10\n";
+    let protocol = Sequence::new()
+        .then(Monotonic::new(0.01, 10))
+        .then(Hold::new(3))
+        .then(PiecewiseLinear::from_table(table).unwrap())
+        .then(Cyclic::new(&[0.01, 0.02], 2));
+    let increments = protocol.increments(0.0);
+    let res = model.simulate_path(0.0, 0.0, &protocol).unwrap();
+    assert_eq!(res.len(), increments.len() + 1);
+
+    // Check the hold steps and the tangents along the whole path
+    for i in 11..14 {
+        assert_eq!(res.records[i].ddx, 0.0);
+        assert_eq!(res.records[i].x, res.records[10].x);
+        approx_eq(res.records[i].y_be, res.records[10].y_be, 1e-8);
+    }
+    for record in &res.records {
+        approx_eq(record.ctm, record.num_ctm, 0.01);
+    }
+
+    // Generate the plot
+    if SAVE_FIGURE {
+        let mut curve_be = Curve::new();
+        curve_be
+            .set_label("Backward Euler")
+            .set_marker_style(".")
+            .draw(&res.xx(), &res.yy_be());
+        let mut curve_ode = Curve::new();
+        curve_ode
+            .set_label("DoPri5")
+            .set_line_style("--")
+            .draw(&res.xx(), &res.yy_ode());
+        let mut plot = Plot::new();
+        plot.add(&curve_be)
+            .add(&curve_ode)
+            .grid_labels_legend("x", "y")
+            .set_figure_size_points(600.0, 300.0)
+            .save("/tmp/ctm_demo/test_loading_protocol.svg")
+            .unwrap();
+    }
+}
@@ -0,0 +1,205 @@
+use ctm_demo::{Bdf2, Error, ImplicitRungeKutta, LocalIntegrator, LocalParams, Model, ModelTrait, ThetaMethod};
+use russell_lab::approx_eq;
+use russell_ode::{Method, Params};
+use std::sync::Arc;
+
+/// Model whose backward Euler residual is r(y) = atan(y - 5) for Δx = 0.1 and y0 = 0
+///
+/// ```text
+/// dy/dx = 10 (y - atan(y - 5))
+/// ```
+struct Arctangent;
+
+impl ModelTrait for Arctangent {
+    fn calc_f(&self, _x: f64, y: f64) -> f64 {
+        10.0 * (y - f64::atan(y - 5.0))
+    }
+
+    fn calc_ll(&self, _x: f64, _y: f64) -> f64 {
+        0.0
+    }
+
+    fn calc_jj(&self, _x: f64, y: f64) -> f64 {
+        10.0 * (1.0 - 1.0 / (1.0 + (y - 5.0) * (y - 5.0)))
+    }
+}
+
+/// Model whose backward Euler residual is r(y) = y³ - 3y - 1 for Δx = 0.1 and y0 = 0
+///
+/// ```text
+/// dy/dx = 10 (4y - y³ + 1)
+/// ```
+struct Cubic;
+
+impl ModelTrait for Cubic {
+    fn calc_f(&self, _x: f64, y: f64) -> f64 {
+        10.0 * (4.0 * y - y * y * y + 1.0)
+    }
+
+    fn calc_ll(&self, _x: f64, _y: f64) -> f64 {
+        0.0
+    }
+
+    fn calc_jj(&self, _x: f64, y: f64) -> f64 {
+        10.0 * (4.0 - 3.0 * y * y)
+    }
+}
+
+fn allocate_model<'a>() -> Model<'a> {
+    Model::from_model(Arc::new(Arctangent), Params::new(Method::DoPri5)).unwrap()
+}
+
+#[test]
+fn test_local_params_safeguards_newton() {
+    // plain Newton from the forward Euler trial diverges (the slope of atan vanishes far from the root)
+    let ddx = 0.1;
+    let model = Arctangent;
+    let mut y = 0.0 + ddx * model.calc_f(ddx, 0.0);
+    for _ in 0..20 {
+        let r = y - ddx * model.calc_f(ddx, y);
+        let dr = 1.0 - ddx * model.calc_jj(ddx, y);
+        y -= r / dr;
+        if !y.is_finite() {
+            break;
+        }
+    }
+    assert!(y.is_nan() || f64::abs(y - 5.0) > 1.0);
+
+    // the safeguarded solver converges with the default parameters
+    let model = allocate_model();
+    let (mut x, mut y) = (0.0, 0.0);
+    let stats = model.backward_euler_update(&mut x, &mut y, ddx).unwrap();
+    println!("n_iterations = {}", stats.n_iterations);
+    approx_eq(y, 5.0, 1e-8);
+    assert!(f64::abs(stats.residual) < model.local_params().abs_tol);
+
+    // ... but not without the line search if the iterations are limited
+    let mut model = allocate_model();
+    let mut params = LocalParams::new();
+    params.line_search = false;
+    params.n_iteration_max = 2;
+    model.set_local_params(params).unwrap();
+    let (mut x, mut y) = (0.0, 0.0);
+    assert!(matches!(
+        model.backward_euler_update(&mut x, &mut y, ddx),
+        Err(Error::LocalNewtonFailure { .. })
+    ));
+}
+
+#[test]
+fn test_local_params_brackets_the_root() {
+    // the slope of the residual vanishes at the trial value y = 1; thus, Newton cannot start
+    let ddx = 0.1;
+    let mut model = Model::from_model(Arc::new(Cubic), Params::new(Method::DoPri5)).unwrap();
+    let mut params = LocalParams::new();
+    params.n_bracket_max = 0;
+    model.set_local_params(params).unwrap();
+    let (mut x, mut y) = (0.0, 0.0);
+    assert!(matches!(
+        model.backward_euler_update(&mut x, &mut y, ddx),
+        Err(Error::LocalNewtonFailure { .. })
+    ));
+
+    // the initial bracketing search finds [1, 4] and enables the bisection safeguard
+    model.set_local_params(LocalParams::new()).unwrap();
+    let (mut x, mut y) = (0.0, 0.0);
+    let stats = model.backward_euler_update(&mut x, &mut y, ddx).unwrap();
+    println!("n_iterations = {}", stats.n_iterations);
+    approx_eq(y, 2.0 * f64::cos(f64::to_radians(20.0)), 1e-8);
+}
+
+#[test]
+fn test_local_params_tolerances() {
+    let ddx = 0.1;
+    let mut n_iterations = Vec::new();
+    for abs_tol in [1e-2, 1e-6, 1e-12] {
+        let mut model = allocate_model();
+        let mut params = LocalParams::new();
+        params.abs_tol = abs_tol;
+        model.set_local_params(params).unwrap();
+        let (mut x, mut y) = (0.0, 0.0);
+        let stats = model.backward_euler_update(&mut x, &mut y, ddx).unwrap();
+        assert!(f64::abs(stats.residual) < abs_tol);
+        n_iterations.push(stats.n_iterations);
+    }
+    assert!(n_iterations.is_sorted());
+
+    // relative tolerance only
+    let mut model = allocate_model();
+    let mut params = LocalParams::new();
+    params.abs_tol = 0.0;
+    params.rel_tol = 1e-10;
+    model.set_local_params(params).unwrap();
+    let (mut x, mut y) = (0.0, 0.0);
+    let stats = model.backward_euler_update(&mut x, &mut y, ddx).unwrap();
+    assert!(f64::abs(stats.residual) < 1e-10 * f64::abs(y));
+}
+
+#[test]
+fn test_local_params_captures_errors() {
+    let mut model = allocate_model();
+    let mut params = LocalParams::new();
+    params.abs_tol = -1.0;
+    assert!(model.set_local_params(params).is_err());
+    params.abs_tol = 0.0;
+    assert!(model.set_local_params(params).is_err());
+    params.abs_tol = 1e-8;
+    params.rel_tol = -1.0;
+    assert_eq!(
+        params.validate().unwrap_err().to_string(),
+        "parameter 'rel_tol' = -1 is invalid: the tolerances must be non-negative"
+    );
+    params.rel_tol = 0.0;
+    params.n_iteration_max = 0;
+    assert!(model.set_local_params(params).is_err());
+    assert_eq!(model.local_params(), &LocalParams::default());
+}
+
+#[test]
+fn test_local_params_apply_to_the_implicit_integrators() {
+    let ddx = 0.1;
+    let integrators: Vec<Box<dyn LocalIntegrator>> = vec![
+        Box::new(ThetaMethod::backward_euler()),
+        Box::new(Bdf2::new()),
+        Box::new(ImplicitRungeKutta::radau_iia2()),
+    ];
+    for mut integrator in integrators {
+        // a single iteration is not enough
+        let mut model = allocate_model();
+        let mut params = LocalParams::new();
+        params.n_iteration_max = 1;
+        model.set_local_params(params).unwrap();
+        let (mut x, mut y) = (0.0, 0.0);
+        match model.local_update(integrator.as_mut(), &mut x, &mut y, ddx) {
+            Err(Error::LocalNewtonFailure { history, .. }) => assert_eq!(history.len(), 1),
+            other => panic!("{}: unexpected result {:?}", integrator.name(), other),
+        }
+
+        // a loose tolerance accepts the forward Euler trial (the initial guess of all stages)
+        params = LocalParams::new();
+        params.abs_tol = 1e3;
+        model.set_local_params(params).unwrap();
+        let (mut x, mut y) = (0.0, 0.0);
+        model.local_update(integrator.as_mut(), &mut x, &mut y, ddx).unwrap();
+        let y_loose = y;
+
+        // the default tolerance solves the equations (y = 5 for backward Euler)
+        model.set_local_params(LocalParams::new()).unwrap();
+        integrator.reset();
+        let (mut x, mut y) = (0.0, 0.0);
+        model.local_update(integrator.as_mut(), &mut x, &mut y, ddx).unwrap();
+        println!("{}: y (loose) = {}, y = {}", integrator.name(), y_loose, y);
+        assert!(f64::abs(y - y_loose) > 0.1);
+    }
+
+    // simulate_path passes the parameters to the added integrators
+    let mut model = allocate_model();
+    let mut params = LocalParams::new();
+    params.n_iteration_max = 1;
+    model.set_local_params(params).unwrap();
+    model.add_integrator(ThetaMethod::crank_nicolson());
+    assert!(matches!(
+        model.simulate(0.0, 0.0, ddx, 1),
+        Err(Error::LocalNewtonFailure { .. })
+    ));
+}
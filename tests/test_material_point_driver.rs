@@ -0,0 +1,109 @@
+mod common;
+
+use common::allocate_hardening_softening;
+use ctm_demo::{Control, JacobianKind, LocalParams, MaterialPointDriver, Model};
+use russell_lab::approx_eq;
+
+fn allocate_model<'a>() -> Model<'a> {
+    let mut model = allocate_hardening_softening();
+    // tight local tolerance such that the stress tolerance of 1e-10 is effective
+    let mut local_params = LocalParams::new();
+    local_params.abs_tol = 1e-12;
+    model.set_local_params(local_params).unwrap();
+    model
+}
+
+#[test]
+fn test_stress_control_reproduces_strain_control() {
+    // Perform a strain-controlled simulation (before the peak)
+    let mut model = allocate_model();
+    let res = model.simulate(0.0, 0.0, 0.01, 8).unwrap();
+    let yy = res.yy_be();
+
+    // Apply the same stress increments with the stress-controlled driver
+    let controls: Vec<_> = yy.windows(2).map(|y| Control::Stress(y[1] - y[0])).collect();
+    let driver = MaterialPointDriver::new(JacobianKind::Consistent);
+    let steps = driver.run(&model, 0.0, 0.0, &controls).unwrap();
+    for (i, step) in steps.iter().enumerate() {
+        approx_eq(step.ddx, 0.01, 1e-10);
+        approx_eq(step.x, res.records[i + 1].x, 1e-10);
+        approx_eq(step.y, yy[i + 1], 1e-10);
+    }
+}
+
+#[test]
+fn test_consistent_tangent_gives_quadratic_convergence() {
+    let model = allocate_model();
+    let (x_ini, y_ini, ddy) = (0.02, 0.15, 0.3);
+    let mut n_iterations = Vec::new();
+    for jacobian in [
+        JacobianKind::Consistent,
+        JacobianKind::Continuous,
+        JacobianKind::Secant,
+        JacobianKind::Initial,
+    ] {
+        let mut driver = MaterialPointDriver::new(jacobian);
+        driver.n_iteration_max = 500;
+        let (mut x, mut y) = (x_ini, y_ini);
+        let step = driver.stress_step(&model, &mut x, &mut y, ddy).unwrap();
+        approx_eq(y, y_ini + ddy, 1e-10);
+        println!(
+            "{:?}: iterations = {}, rates = {:?}",
+            jacobian,
+            step.n_iterations,
+            step.rates()
+        );
+        if jacobian == JacobianKind::Consistent {
+            let rates = step.rates();
+            assert!(rates.len() >= 2);
+            assert!(rates[rates.len() - 2] > 1.8);
+        }
+        n_iterations.push(step.n_iterations);
+    }
+    assert!(n_iterations[0] <= 6);
+    assert!(n_iterations[1..].iter().all(|&n| n > n_iterations[0]));
+}
+
+#[test]
+fn test_mixed_control() {
+    let model = allocate_model();
+    let driver = MaterialPointDriver::new(JacobianKind::Consistent);
+    let controls = [
+        Control::Strain(0.03),
+        Control::Stress(0.1),
+        Control::Strain(-0.01),
+        Control::Stress(-0.2),
+    ];
+    let steps = driver.run(&model, 0.0, 0.0, &controls).unwrap();
+    let (mut x, mut y) = (0.0, 0.0);
+    for step in &steps {
+        match step.control {
+            Control::Strain(ddx) => {
+                assert_eq!(step.n_iterations, 0);
+                approx_eq(step.ddx, ddx, 1e-15);
+            }
+            Control::Stress(ddy) => {
+                approx_eq(step.y - y, ddy, 1e-10);
+            }
+        }
+        x += step.ddx;
+        y = step.y;
+        approx_eq(step.x, x, 1e-15);
+    }
+}
+
+#[test]
+fn test_stress_tolerance_respects_the_local_solver() {
+    let mut model = allocate_model();
+    let mut driver = MaterialPointDriver::new(JacobianKind::Consistent);
+    assert_eq!(driver.effective_tolerance(&model, 0.5), 1e-10);
+
+    // a stress tolerance below the noise of the local solver is replaced by the noise level
+    model.set_local_params(LocalParams::new()).unwrap();
+    driver.tolerance = 1e-15;
+    approx_eq(driver.effective_tolerance(&model, 0.5), 1e-7, 1e-20);
+    let (mut x, mut y) = (0.02, 0.15);
+    let step = driver.stress_step(&model, &mut x, &mut y, 0.3).unwrap();
+    assert!(step.n_iterations <= 6);
+    assert!(step.residuals.last().unwrap() < &1e-7);
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use common::allocate_hardening_softening;
+use ctm_demo::{DifferenceScheme, Error, LocalParams};
+
+#[test]
+fn test_numerical_tangent_schemes() {
+    // coarse increments (see test_hardening_softening_curve_coarse)
+    let (ddx, nd) = (0.05, 10);
+    let mut max_errors = Vec::new();
+    for scheme in [
+        DifferenceScheme::Forward,
+        DifferenceScheme::Central,
+        DifferenceScheme::Central4,
+        DifferenceScheme::Richardson,
+    ] {
+        let mut model = allocate_hardening_softening();
+        model.set_numerical_tangent(scheme, None).unwrap();
+        model.set_ode_tolerances(1e-4, 1e-4).unwrap();
+        let res = model.simulate(0.0, 0.0, ddx, nd).unwrap();
+        let mut max_error: f64 = 0.0;
+        for r in &res.records[1..] {
+            // the estimate bounds the actual error (up to a safety factor)
+            let error = f64::abs(r.ctm - r.num_ctm);
+            assert!(error <= 2.0 * r.num_ctm_error);
+            max_error = f64::max(max_error, error);
+        }
+        println!("{:?}: max error = {:e}", scheme, max_error);
+        max_errors.push(max_error);
+    }
+    assert!(max_errors[0] > 1e-4);
+    assert!(max_errors[1] < 1e-5);
+    assert!(max_errors[2] < 1e-6);
+    assert!(max_errors[3] < 1e-5);
+}
+
+#[test]
+fn test_numerical_tangent_fixed_step() {
+    // the default is the forward difference with h = 1e-5
+    let mut model = allocate_hardening_softening();
+    let (x0, y0, ddx) = (0.0, 0.0, 0.05);
+    let forward = model.numerical_tangent(x0, y0, ddx, false).unwrap();
+    assert_eq!(forward.step, 1e-5);
+    assert_eq!(
+        model.numerical_consistent_tangent_modulus(x0, y0, ddx, false).unwrap(),
+        forward.value
+    );
+
+    // central differences are more accurate with the same step
+    model
+        .set_numerical_tangent(DifferenceScheme::Central, Some(1e-5))
+        .unwrap();
+    let central = model.numerical_tangent(x0, y0, ddx, false).unwrap();
+    let (mut x1, mut y1) = (x0, y0);
+    let ctm = model.backward_euler_update(&mut x1, &mut y1, ddx).unwrap().ctm;
+    assert!(f64::abs(central.value - ctm) < 1e-3 * f64::abs(forward.value - ctm));
+    assert!(central.error < forward.error);
+
+    // errors
+    assert!(
+        model
+            .set_numerical_tangent(DifferenceScheme::Central, Some(0.0))
+            .is_err()
+    );
+    assert!(
+        model
+            .set_numerical_tangent(DifferenceScheme::Central, Some(f64::NAN))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_numerical_tangent_noise_level_of_each_route() {
+    let mut model = allocate_hardening_softening();
+    model.set_numerical_tangent(DifferenceScheme::Central, None).unwrap();
+    let scheme = DifferenceScheme::Central;
+    let (x0, y0, ddx) = (0.0, 0.5, 0.05);
+
+    // backward Euler: tolerances of the local solver
+    let mut params = LocalParams::new();
+    params.abs_tol = 1e-12;
+    params.rel_tol = 1e-10;
+    model.set_local_params(params).unwrap();
+    let be = model.numerical_tangent(x0, y0, ddx, false).unwrap();
+    assert_eq!(be.step, scheme.auto_step(ddx, 1e-12 + 1e-10 * y0));
+
+    // ODE: the tolerances given through Params are unknown, thus they must be set
+    assert_eq!(
+        model.numerical_tangent(x0, y0, ddx, true).err(),
+        Some(Error::Unsupported(
+            "the automatic step of the ODE route requires Model::set_ode_tolerances".to_string()
+        ))
+    );
+    model.set_ode_tolerances(1e-4, 1e-4).unwrap();
+    let ode_loose = model.numerical_tangent(x0, y0, ddx, true).unwrap();
+    assert_eq!(ode_loose.step, scheme.auto_step(ddx, 1e-4 + 1e-4 * y0));
+    model.set_ode_tolerances(1e-10, 1e-10).unwrap();
+    let ode = model.numerical_tangent(x0, y0, ddx, true).unwrap();
+    assert_eq!(ode.step, scheme.auto_step(ddx, 1e-10 + 1e-10 * y0));
+
+    // the tight ODE tolerances yield a tangent close to the forward sensitivity
+    let (mut x, mut y) = (x0, y0);
+    let ctm_ode = model.ode_update_with_sensitivity(&mut x, &mut y, ddx).unwrap();
+    println!(
+        "sensitivity = {}, FD (loose) = {}, FD = {}",
+        ctm_ode, ode_loose.value, ode.value
+    );
+    assert!(f64::abs(ode.value - ctm_ode) < 1e-5);
+    assert!(f64::abs(ode.value - ctm_ode) < f64::abs(ode_loose.value - ctm_ode));
+    assert!(model.set_ode_tolerances(0.0, 1e-10).is_err());
+}
@@ -0,0 +1,74 @@
+use ctm_demo::{Dahlquist, Model, ModelType};
+use russell_lab::approx_eq;
+use russell_ode::{Method, Params};
+use std::collections::HashMap;
+
+fn all_methods() -> Vec<Method> {
+    let mut methods = vec![Method::Radau5, Method::BwEuler, Method::FwEuler];
+    methods.extend(Method::erk_methods());
+    methods
+}
+
+#[test]
+fn test_all_ode_methods_dahlquist() {
+    let lambda = 5.0;
+    let (ddx, nd) = (0.05, 10);
+    for method in all_methods() {
+        let mut params = Params::new(method);
+        params.set_tolerances(1e-10, 1e-10, None).unwrap();
+        let mut model = Model::new(ModelType::Dahlquist, HashMap::from([("lambda", lambda)]), params).unwrap();
+        let res = model.simulate(0.0, 1.0, ddx, nd).unwrap();
+        let last = res.records.last().unwrap();
+        let error = f64::abs(last.y_ode - Dahlquist::analytical_y(lambda, last.x));
+        println!("{:>10}: error = {:.2e}", format!("{:?}", method), error);
+        let info = method.information();
+        let tol = if info.embedded {
+            1e-6
+        } else {
+            f64::powi(0.1, info.order as i32 + 1)
+        };
+        assert!(error < tol);
+    }
+}
+
+#[test]
+fn test_analytical_jacobian_matches_numerical_jacobian() {
+    let params_map = HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]);
+    let (ddx, nd) = (0.02, 30);
+    for method in [Method::Radau5, Method::BwEuler] {
+        let mut results = Vec::new();
+        for numerical in [false, true] {
+            let mut params = Params::new(method);
+            params.newton.use_numerical_jacobian = numerical;
+            params.set_tolerances(1e-10, 1e-10, None).unwrap();
+            let mut model = Model::new(ModelType::HardeningSoftening, params_map.clone(), params).unwrap();
+            let res = model.simulate(0.0, 0.0, ddx, nd).unwrap();
+            let n_function: usize = res
+                .records
+                .iter()
+                .filter_map(|r| r.ode_stats)
+                .map(|s| s.n_function)
+                .sum();
+            results.push((res, n_function));
+        }
+
+        // the analytical Jacobian saves the function evaluations of the numerical Jacobian
+        let ((ana, n_function_ana), (num, n_function_num)) = (&results[0], &results[1]);
+        println!(
+            "{:?}: n_function (analytical) = {}, n_function (numerical) = {}",
+            method, n_function_ana, n_function_num
+        );
+        assert!(n_function_ana < n_function_num);
+        for (a, b) in ana.records.iter().zip(&num.records) {
+            approx_eq(a.y_ode, b.y_ode, 1e-8);
+            approx_eq(a.num_ctm_ode, b.num_ctm_ode, 1e-2);
+        }
+    }
+}
+
+#[test]
+fn test_ode_params_are_validated() {
+    let mut params = Params::new(Method::DoPri5);
+    params.step.h_ini = 0.0;
+    assert!(Model::new(ModelType::Dahlquist, HashMap::from([("lambda", 1.0)]), params).is_err());
+}
@@ -0,0 +1,122 @@
+use ctm_demo::{Dahlquist, Model, ModelType};
+use plotpy::{Curve, Plot};
+use russell_lab::approx_eq;
+use russell_ode::{Method, Params};
+use std::collections::HashMap;
+
+const SAVE_FIGURE: bool = false;
+
+#[test]
+fn test_ode_sensitivity_dahlquist() {
+    // Exact ODE route: y1 = y0 exp(-λ Δx)  thus  dy1/dΔx = -λ y1
+    let lambda = 5.0;
+    let mut params = Params::new(Method::DoPri5);
+    params.set_tolerances(1e-10, 1e-10, None).unwrap();
+    let mut model = Model::new(ModelType::Dahlquist, HashMap::from([("lambda", lambda)]), params).unwrap();
+    model.set_ode_sensitivity(true);
+    let (ddx, nd) = (0.1, 10);
+    let res = model.simulate(0.0, 1.0, ddx, nd).unwrap();
+    let mut max_err_sensitivity: f64 = 0.0;
+    let mut max_err_finite_difference: f64 = 0.0;
+    for i in 1..nd + 1 {
+        let y0 = res.records[i - 1].y_be;
+        let correct = -lambda * y0 * f64::exp(-lambda * ddx);
+        max_err_sensitivity = f64::max(max_err_sensitivity, f64::abs(res.records[i].ctm_ode.unwrap() - correct));
+        max_err_finite_difference = f64::max(
+            max_err_finite_difference,
+            f64::abs(res.records[i].num_ctm_ode - correct),
+        );
+    }
+    println!("max error (sensitivity)       = {:.2e}", max_err_sensitivity);
+    println!("max error (finite difference) = {:.2e}", max_err_finite_difference);
+    assert!(max_err_sensitivity < 1e-8);
+    assert!(max_err_sensitivity < max_err_finite_difference);
+
+    // The updated state equals the one from the plain ODE update
+    let (mut xa, mut ya) = (0.0, 1.0);
+    let (mut xb, mut yb) = (0.0, 1.0);
+    model.ode_update(&mut xa, &mut ya, ddx).unwrap();
+    model.ode_update_with_sensitivity(&mut xb, &mut yb, ddx).unwrap();
+    assert_eq!(xa, xb);
+    approx_eq(yb, Dahlquist::analytical_y(lambda, ddx), 1e-9);
+    approx_eq(ya, yb, 1e-9);
+}
+
+#[test]
+fn test_ode_sensitivity_hardening_softening() {
+    // Allocate the model
+    let params_map = HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]);
+    let mut params = Params::new(Method::DoPri5);
+    params.set_tolerances(1e-10, 1e-10, None).unwrap();
+    let mut model = Model::new(ModelType::HardeningSoftening, params_map.clone(), params).unwrap();
+    model.set_ode_sensitivity(true);
+
+    // Compare with a central difference of the ODE update
+    let (ddx, nd) = (0.05, 12);
+    let res = model.simulate(0.0, 0.0, ddx, nd).unwrap();
+    let h = 1e-4;
+    for i in 1..nd + 1 {
+        let (x0, y0) = (res.records[i - 1].x, res.records[i - 1].y_be);
+        let (mut xa, mut ya) = (x0, y0);
+        let (mut xb, mut yb) = (x0, y0);
+        model.ode_update(&mut xa, &mut ya, ddx - h).unwrap();
+        model.ode_update(&mut xb, &mut yb, ddx + h).unwrap();
+        let central = (yb - ya) / (2.0 * h);
+        approx_eq(res.records[i].ctm_ode.unwrap(), central, 1e-5);
+        approx_eq(res.records[i].ctm_ode.unwrap(), res.records[i].num_ctm_ode, 1e-3);
+    }
+
+    // Radau5 yields the same tangent (the finite difference of its adaptive steps is noisier)
+    let mut params = Params::new(Method::Radau5);
+    params.set_tolerances(1e-10, 1e-10, None).unwrap();
+    let mut model = Model::new(ModelType::HardeningSoftening, params_map, params).unwrap();
+    model.set_ode_sensitivity(true);
+    let res_radau = model.simulate(0.0, 0.0, ddx, nd).unwrap();
+    for i in 1..nd + 1 {
+        approx_eq(
+            res_radau.records[i].ctm_ode.unwrap(),
+            res.records[i].ctm_ode.unwrap(),
+            1e-6,
+        );
+    }
+
+    // Plot the results
+    if SAVE_FIGURE {
+        let mut curve1 = Curve::new();
+        let mut curve2 = Curve::new();
+        let mut curve3 = Curve::new();
+        curve1
+            .set_label("CTM (backward Euler)")
+            .draw(&res.xx(), &res.ctm_list());
+        curve2
+            .set_label("CTM (ODE, sensitivity)")
+            .set_line_style("None")
+            .set_marker_style("o")
+            .draw(&res.xx(), &res.ctm_ode_list());
+        curve3
+            .set_label("Numerical CTM (ODE, Radau5)")
+            .set_line_style("None")
+            .set_marker_style("+")
+            .draw(&res_radau.xx(), &res_radau.num_ctm_ode_list());
+        let mut plot = Plot::new();
+        plot.add(&curve1)
+            .add(&curve2)
+            .add(&curve3)
+            .grid_and_labels("x", "dy/dx")
+            .legend()
+            .save("/tmp/consistent_tangent/test_ode_sensitivity.svg")
+            .unwrap();
+    }
+}
+
+#[test]
+fn test_ode_sensitivity_is_optional() {
+    let params_map = HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]);
+    let mut model = Model::new(ModelType::HardeningSoftening, params_map, Params::new(Method::DoPri5)).unwrap();
+    let res = model.simulate(0.0, 0.0, 0.05, 3).unwrap();
+    assert!(res.records.iter().all(|r| r.ctm_ode.is_none()));
+    assert!(res.ctm_ode_list().iter().all(|c| c.is_nan()));
+    model.set_ode_sensitivity(true);
+    let res = model.simulate(0.0, 0.0, 0.05, 3).unwrap();
+    assert!(res.records.iter().all(|r| r.ctm_ode.is_some()));
+}
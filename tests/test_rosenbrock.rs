@@ -0,0 +1,113 @@
+use ctm_demo::{LocalIntegrator, Model, ModelTrait, ModelType, Rosenbrock};
+use plotpy::{Curve, Plot};
+use russell_lab::approx_eq;
+use russell_ode::{Method, Params};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const SAVE_FIGURE: bool = false;
+
+/// Non-autonomous linear model with dy/dx = cos(x) - y
+///
+/// With y(0) = 0, the solution is y(x) = (cos(x) + sin(x) - exp(-x)) / 2
+struct Forced;
+
+impl ModelTrait for Forced {
+    fn calc_f(&self, x: f64, y: f64) -> f64 {
+        f64::cos(x) - y
+    }
+
+    fn calc_ll(&self, x: f64, _y: f64) -> f64 {
+        -f64::sin(x)
+    }
+
+    fn calc_jj(&self, _x: f64, _y: f64) -> f64 {
+        -1.0
+    }
+}
+
+#[test]
+fn test_rosenbrock_order_non_autonomous() {
+    let x_end = 2.0;
+    let y_end = (f64::cos(x_end) + f64::sin(x_end) - f64::exp(-x_end)) / 2.0;
+    for mut scheme in [Rosenbrock::ros2(), Rosenbrock::ros3p()] {
+        let mut errors = Vec::new();
+        for nd in [80, 160] {
+            let model = Model::from_model(Arc::new(Forced), Params::new(Method::DoPri5)).unwrap();
+            let ddx = x_end / (nd as f64);
+            let (mut x, mut y) = (0.0, 0.0);
+            for _ in 0..nd {
+                model.local_update(&mut scheme, &mut x, &mut y, ddx).unwrap();
+            }
+            errors.push(f64::abs(y - y_end));
+        }
+        let order = f64::log2(errors[0] / errors[1]);
+        println!("{:>5}: order = {:.3}", scheme.name(), order);
+        assert!(f64::abs(order - scheme.order() as f64) < 0.15);
+    }
+}
+
+#[test]
+fn test_rosenbrock_tangents_match_finite_differences() {
+    let params = HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]);
+    let mut model = Model::new(ModelType::HardeningSoftening, params, Params::new(Method::DoPri5)).unwrap();
+    let (ddx, nd) = (0.05, 12);
+    let res = model.simulate(0.0, 0.0, ddx, nd).unwrap();
+    let h = 1e-5;
+    for mut scheme in [Rosenbrock::ros2(), Rosenbrock::ros3p()] {
+        for i in 1..nd + 1 {
+            let (x0, y0) = (res.records[i - 1].x, res.records[i - 1].y_be);
+            let (mut x, mut y) = (x0, y0);
+            let ctm = model.local_update(&mut scheme, &mut x, &mut y, ddx).unwrap();
+            let (mut xa, mut ya) = (x0, y0);
+            let (mut xb, mut yb) = (x0, y0);
+            model.local_update(&mut scheme, &mut xa, &mut ya, ddx - h).unwrap();
+            model.local_update(&mut scheme, &mut xb, &mut yb, ddx + h).unwrap();
+            approx_eq(ctm, (yb - ya) / (2.0 * h), 1e-5);
+        }
+    }
+}
+
+#[test]
+fn test_rosenbrock_compared_with_backward_euler() {
+    let params = HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]);
+    let mut ode_params = Params::new(Method::DoPri5);
+    ode_params.set_tolerances(1e-10, 1e-10, None).unwrap();
+    let mut model = Model::new(ModelType::HardeningSoftening, params, ode_params).unwrap();
+    model
+        .add_integrator(Rosenbrock::ros2())
+        .add_integrator(Rosenbrock::ros3p());
+    let res = model.simulate(0.0, 0.0, 0.02, 40).unwrap();
+
+    // Rosenbrock needs no iterations and is closer to the ODE solution than backward Euler
+    let max_error = |yy: &[f64], zz: &[f64]| {
+        yy.iter()
+            .zip(zz)
+            .fold(0.0, |acc, (y, z)| f64::max(acc, f64::abs(y - z)))
+    };
+    let yy_ode = res.yy_ode();
+    let error_be = max_error(&res.yy_be(), &yy_ode);
+    for integrator in &res.integrators {
+        let error = max_error(&integrator.yy, &yy_ode);
+        println!("{:>5}: error = {:.2e} (BE: {:.2e})", integrator.name, error, error_be);
+        assert!(error < error_be);
+    }
+
+    // Plot the results
+    if SAVE_FIGURE {
+        let xx = res.xx();
+        let mut curve = Curve::new();
+        curve.set_label("backward Euler").draw(&xx, &res.yy_be());
+        let mut plot = Plot::new();
+        plot.add(&curve);
+        for integrator in &res.integrators {
+            let mut curve = Curve::new();
+            curve.set_label(&integrator.name).draw(&xx, &integrator.yy);
+            plot.add(&curve);
+        }
+        plot.grid_and_labels("x", "y")
+            .legend()
+            .save("/tmp/consistent_tangent/test_rosenbrock.svg")
+            .unwrap();
+    }
+}
@@ -0,0 +1,151 @@
+use ctm_demo::{Model, ModelTrait, SolvabilityParams};
+use russell_lab::approx_eq;
+use russell_ode::{Method, Params};
+use std::sync::Arc;
+
+/// Quadratic model whose backward Euler residual has zero or two roots
+///
+/// ```text
+/// dy/dx = y² + x
+/// r(y) = y - y0 - Δx (y² + x1)
+/// ```
+struct Quadratic;
+
+impl ModelTrait for Quadratic {
+    fn calc_f(&self, x: f64, y: f64) -> f64 {
+        y * y + x
+    }
+
+    fn calc_ll(&self, _x: f64, _y: f64) -> f64 {
+        1.0
+    }
+
+    fn calc_jj(&self, _x: f64, y: f64) -> f64 {
+        2.0 * y
+    }
+}
+
+/// Quadratic model whose Jacobian depends on x
+///
+/// ```text
+/// dy/dx = x y²
+/// ```
+struct ScaledQuadratic;
+
+impl ModelTrait for ScaledQuadratic {
+    fn calc_f(&self, x: f64, y: f64) -> f64 {
+        x * y * y
+    }
+
+    fn calc_ll(&self, _x: f64, y: f64) -> f64 {
+        y * y
+    }
+
+    fn calc_jj(&self, x: f64, y: f64) -> f64 {
+        2.0 * x * y
+    }
+}
+
+fn analytical_roots(y0: f64, ddx: f64, x1: f64) -> Vec<f64> {
+    let disc = 1.0 - 4.0 * ddx * (y0 + ddx * x1);
+    if disc < 0.0 {
+        return Vec::new();
+    }
+    vec![(1.0 - disc.sqrt()) / (2.0 * ddx), (1.0 + disc.sqrt()) / (2.0 * ddx)]
+}
+
+fn allocate_model<'a>() -> Model<'a> {
+    Model::from_model(Arc::new(Quadratic), Params::new(Method::DoPri5)).unwrap()
+}
+
+#[test]
+fn test_solvability_analysis() {
+    let model = allocate_model();
+
+    // two roots: the second one is spurious
+    let ddx = 0.1;
+    let res = model.analyze_solvability(0.0, 1.0, ddx).unwrap();
+    println!("{:?}", res);
+    let correct = analytical_roots(1.0, ddx, ddx);
+    assert_eq!(res.roots.len(), 2);
+    approx_eq(res.roots[0], correct[0], 1e-13);
+    approx_eq(res.roots[1], correct[1], 1e-13);
+    assert!(!res.is_well_posed());
+    assert!(!res.is_unique());
+
+    // below Δx_max, the spurious root lies beyond the scanned interval (J = 2y does not depend on x)
+    approx_eq(res.ddx_max, 1.0 / (2.0 * res.y_max), 1e-13);
+    let correct = analytical_roots(1.0, 0.99 * res.ddx_max, 0.99 * res.ddx_max);
+    assert_eq!(correct.len(), 2);
+    assert!(correct[0] < res.y_max && correct[1] > res.y_max);
+
+    // no roots
+    let res = model.analyze_solvability(0.0, 1.0, 0.4).unwrap();
+    assert_eq!(analytical_roots(1.0, 0.4, 0.4).len(), 0);
+    assert_eq!(res.roots.len(), 0);
+    assert!(!res.is_well_posed());
+
+    // small increment: the spurious root lies far away from the scanned interval
+    let ddx = 0.01;
+    let res = model.analyze_solvability(0.0, 1.0, ddx).unwrap();
+    let correct = analytical_roots(1.0, ddx, ddx);
+    assert!(correct[1] > res.y_max);
+    assert_eq!(res.roots.len(), 1);
+    approx_eq(res.roots[0], correct[0], 1e-13);
+    assert!(res.is_well_posed());
+
+    // a narrower scan may hide the spurious root
+    let mut model = allocate_model();
+    model
+        .set_solvability_check(Some(SolvabilityParams { span: 1.0, n_scan: 100 }))
+        .unwrap();
+    let res = model.analyze_solvability(0.0, 1.0, 0.1).unwrap();
+    assert_eq!(res.roots.len(), 1);
+    assert!(
+        model
+            .set_solvability_check(Some(SolvabilityParams {
+                span: -1.0,
+                n_scan: 100
+            }))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_solvability_largest_admissible_increment() {
+    // with J = 2 x y, the admissible increment solves 2 y_max Δx (x0 + Δx) = 1
+    let model = Model::from_model(Arc::new(ScaledQuadratic), Params::new(Method::DoPri5)).unwrap();
+    let (x0, ddx) = (1.0, 0.1);
+    let res = model.analyze_solvability(x0, 1.0, ddx).unwrap();
+    let correct = (-x0 + f64::sqrt(x0 * x0 + 2.0 / res.y_max)) / 2.0;
+    println!("ddx_max = {}, correct = {}", res.ddx_max, correct);
+    approx_eq(res.ddx_max, correct, 1e-13);
+
+    // the bound with J held at x1 = x0 + Δx is different (here, too conservative)
+    let ddx_max_local = 1.0 / (2.0 * (x0 + ddx) * res.y_max);
+    assert!(ddx_max_local < res.ddx_max - 1e-3);
+}
+
+#[test]
+fn test_solvability_simulate_warns() {
+    // disabled by default
+    let mut model = allocate_model();
+    let res = model.simulate(0.0, 1.0, 0.1, 2).unwrap();
+    assert!(res.ill_posed.is_empty());
+
+    // each update has a spurious root; backward Euler picks the physical one
+    model.set_solvability_check(Some(SolvabilityParams::new())).unwrap();
+    let res = model.simulate(0.0, 1.0, 0.1, 2).unwrap();
+    assert_eq!(res.ill_posed.len(), 2);
+    for (index, analysis) in &res.ill_posed {
+        let r = &res.records[*index];
+        assert_eq!(analysis.ddx, r.ddx);
+        assert_eq!(analysis.roots.len(), 2);
+        approx_eq(r.y_be, analysis.roots[0], 1e-8);
+        assert!(f64::abs(r.y_be - r.y_ode) < f64::abs(analysis.roots[1] - r.y_ode));
+    }
+
+    // small increments are well posed
+    let res = model.simulate(0.0, 1.0, 0.01, 5).unwrap();
+    assert!(res.ill_posed.is_empty());
+}
@@ -0,0 +1,105 @@
+use ctm_demo::{Dahlquist, Model, ModelType, Substepping};
+use plotpy::{Curve, Plot};
+use russell_lab::approx_eq;
+use russell_ode::{Method, Params};
+use std::collections::HashMap;
+
+const SAVE_FIGURE: bool = false;
+
+#[test]
+fn test_substepping_dahlquist() {
+    // Tighter tolerances need more substeps and yield smaller errors
+    let lambda = 5.0;
+    let model = Model::new(
+        ModelType::Dahlquist,
+        HashMap::from([("lambda", lambda)]),
+        Params::new(Method::DoPri5),
+    )
+    .unwrap();
+    let ddx = 0.5;
+    let mut previous = (0, f64::INFINITY);
+    for tolerance in [1e-3, 1e-5, 1e-7] {
+        let mut scheme = Substepping::new(tolerance);
+        let (mut x, mut y) = (0.0, 1.0);
+        let ctm = model.local_update(&mut scheme, &mut x, &mut y, ddx).unwrap();
+        let error = f64::abs(y - Dahlquist::analytical_y(lambda, ddx));
+        let error_ctm = f64::abs(ctm + lambda * Dahlquist::analytical_y(lambda, ddx));
+        println!(
+            "STOL = {:e}: substeps = {:>3} (rejected = {}), error = {:.2e}, CTM error = {:.2e}",
+            tolerance,
+            scheme.n_accepted(),
+            scheme.n_rejected(),
+            error,
+            error_ctm
+        );
+        assert!(scheme.n_accepted() > previous.0);
+        assert!(error < previous.1);
+        assert!(error < 10.0 * tolerance);
+        assert!(error_ctm < 100.0 * tolerance);
+        previous = (scheme.n_accepted(), error);
+    }
+}
+
+#[test]
+fn test_substepping_tangents_match_finite_differences() {
+    let params = HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]);
+    let mut model = Model::new(ModelType::HardeningSoftening, params, Params::new(Method::DoPri5)).unwrap();
+    let (ddx, nd) = (0.05, 12);
+    let res = model.simulate(0.0, 0.0, ddx, nd).unwrap();
+    let h = 1e-6;
+    let mut scheme = Substepping::new(1e-6);
+    for i in 1..nd + 1 {
+        let (x0, y0) = (res.records[i - 1].x, res.records[i - 1].y_be);
+        let (mut x, mut y) = (x0, y0);
+        let ctm = model.local_update(&mut scheme, &mut x, &mut y, ddx).unwrap();
+        let (mut xa, mut ya) = (x0, y0);
+        let (mut xb, mut yb) = (x0, y0);
+        model.local_update(&mut scheme, &mut xa, &mut ya, ddx - h).unwrap();
+        model.local_update(&mut scheme, &mut xb, &mut yb, ddx + h).unwrap();
+        // the substep fractions depend on Δx; thus, the finite difference is perturbed by the local error
+        approx_eq(ctm, (yb - ya) / (2.0 * h), 1e-3);
+    }
+}
+
+#[test]
+fn test_substepping_hardening_softening() {
+    let params = HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]);
+    let mut ode_params = Params::new(Method::DoPri5);
+    ode_params.set_tolerances(1e-10, 1e-10, None).unwrap();
+    let mut model = Model::new(ModelType::HardeningSoftening, params, ode_params).unwrap();
+    model.set_ode_sensitivity(true);
+    model.add_integrator(Substepping::new(1e-6));
+    let res = model.simulate(0.0, 0.0, 0.05, 16).unwrap();
+
+    // Substepping is close to the ODE solution and its tangent (given by the forward sensitivity)
+    let sub = &res.integrators[0];
+    for i in 1..res.len() {
+        approx_eq(sub.yy[i], res.records[i].y_ode, 1e-5);
+        let (mut x, mut y) = (res.records[i - 1].x, sub.yy[i - 1]);
+        let ctm_ode = model.ode_update_with_sensitivity(&mut x, &mut y, 0.05).unwrap();
+        approx_eq(sub.ctm[i], ctm_ode, 1e-4);
+    }
+
+    // Plot the results
+    if SAVE_FIGURE {
+        let xx = res.xx();
+        let mut curve1 = Curve::new();
+        let mut curve2 = Curve::new();
+        let mut curve3 = Curve::new();
+        curve1.set_label("CTM (backward Euler)").draw(&xx, &res.ctm_list());
+        curve2.set_label("CTM (ODE)").draw(&xx, &res.ctm_ode_list());
+        curve3
+            .set_label("CTM (substepping)")
+            .set_marker_style("o")
+            .set_line_style("None")
+            .draw(&xx, &sub.ctm);
+        let mut plot = Plot::new();
+        plot.add(&curve1)
+            .add(&curve2)
+            .add(&curve3)
+            .grid_and_labels("x", "dy/dx")
+            .legend()
+            .save("/tmp/consistent_tangent/test_substepping.svg")
+            .unwrap();
+    }
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use common::allocate_hardening_softening;
+use ctm_demo::{
+    Bdf2, ExponentialEuler, ImplicitRungeKutta, LocalIntegrator, LocalParams, Model, ModelTrait, ModelType, Rosenbrock,
+    Substepping, TaylorTest, ThetaMethod,
+};
+use russell_ode::{Method, Params};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const TOL: f64 = 0.05;
+
+fn allocate_model<'a>() -> Model<'a> {
+    // tight tolerances keep the remainders above the noise of the updates
+    let mut model = allocate_hardening_softening();
+    model.set_ode_tolerances(1e-12, 1e-12).unwrap();
+    let mut local_params = LocalParams::new();
+    local_params.abs_tol = 1e-14;
+    model.set_local_params(local_params).unwrap();
+    model
+}
+
+fn check(name: &str, test: &TaylorTest) {
+    println!("{:>28}: order = {:.3}", name, test.observed_order());
+    assert!(test.passed(TOL), "{} failed the Taylor test: {:?}", name, test);
+}
+
+#[test]
+fn test_taylor_test_all_schemes() {
+    let mut model = allocate_model();
+    let ddx = 0.05;
+    let res = model.simulate(0.0, 0.0, ddx, 10).unwrap();
+    for i in 1..res.len() {
+        let (x0, y0) = (res.records[i - 1].x, res.records[i - 1].y_be);
+        println!("x0 = {:.2}", x0);
+        check(
+            "backward Euler",
+            &model.taylor_test_backward_euler(x0, y0, ddx).unwrap(),
+        );
+        check("ODE (sensitivity)", &model.taylor_test_ode(x0, y0, ddx).unwrap());
+        let theta = ThetaMethod::crank_nicolson();
+        check(&theta.name(), &model.taylor_test_local(&theta, x0, y0, ddx).unwrap());
+        let irk = ImplicitRungeKutta::radau_iia2();
+        check(&irk.name(), &model.taylor_test_local(&irk, x0, y0, ddx).unwrap());
+        let ros = Rosenbrock::ros3p();
+        check(&ros.name(), &model.taylor_test_local(&ros, x0, y0, ddx).unwrap());
+        let ee = ExponentialEuler::new();
+        check(&ee.name(), &model.taylor_test_local(&ee, x0, y0, ddx).unwrap());
+
+        // BDF2 with the history of the previous increment
+        let mut bdf = Bdf2::new();
+        if i > 1 {
+            let (mut x, mut y) = (res.records[i - 2].x, res.records[i - 2].y_be);
+            model.local_update(&mut bdf, &mut x, &mut y, ddx).unwrap();
+            assert!(bdf.history().is_some());
+        }
+        check(&bdf.name(), &model.taylor_test_local(&bdf, x0, y0, ddx).unwrap());
+
+        // a single substep (the pattern of adaptive substeps changes with Δx, degrading the order)
+        let substepping = Substepping::new(1.0);
+        check(
+            &substepping.name(),
+            &model.taylor_test_local(&substepping, x0, y0, ddx).unwrap(),
+        );
+    }
+}
+
+/// Dahlquist model with a 50% error in J
+struct WrongJacobian;
+
+impl ModelTrait for WrongJacobian {
+    fn calc_f(&self, _x: f64, y: f64) -> f64 {
+        -5.0 * y
+    }
+
+    fn calc_ll(&self, _x: f64, _y: f64) -> f64 {
+        0.0
+    }
+
+    fn calc_jj(&self, _x: f64, _y: f64) -> f64 {
+        -5.0 * 1.5
+    }
+}
+
+#[test]
+fn test_taylor_test_detects_wrong_tangents() {
+    let model = Model::from_model(Arc::new(WrongJacobian), Params::new(Method::DoPri5)).unwrap();
+    let test = model.taylor_test_backward_euler(0.0, 1.0, 0.1).unwrap();
+    println!("{:?}", test);
+    assert!((test.observed_order() - 1.0).abs() < 0.1);
+    assert!(!test.passed(0.5));
+
+    // the correct model passes (with round-off remainders since the update is exact for ε → 0)
+    let model = Model::new(
+        ModelType::Dahlquist,
+        HashMap::from([("lambda", 5.0)]),
+        Params::new(Method::DoPri5),
+    )
+    .unwrap();
+    let test = model.taylor_test_backward_euler(0.0, 1.0, 0.1).unwrap();
+    assert!(test.passed(TOL));
+}
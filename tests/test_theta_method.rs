@@ -0,0 +1,136 @@
+use ctm_demo::{Dahlquist, LocalIntegrator, Model, ModelType, ThetaMethod, ThetaVariant};
+use plotpy::{Curve, Plot};
+use russell_lab::approx_eq;
+use russell_ode::{Method, Params};
+use std::collections::HashMap;
+
+const SAVE_FIGURE: bool = false;
+
+fn all_schemes() -> Vec<ThetaMethod> {
+    vec![
+        ThetaMethod::forward_euler(),
+        ThetaMethod::crank_nicolson(),
+        ThetaMethod::midpoint(),
+        ThetaMethod::backward_euler(),
+        ThetaMethod::new(0.75, ThetaVariant::Trapezoidal).unwrap(),
+        ThetaMethod::new(0.75, ThetaVariant::Midpoint).unwrap(),
+    ]
+}
+
+#[test]
+fn test_theta_method_backward_euler_matches_model() {
+    let params = HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]);
+    let mut model = Model::new(ModelType::HardeningSoftening, params, Params::new(Method::DoPri5)).unwrap();
+    model
+        .add_integrator(ThetaMethod::backward_euler())
+        .add_integrator(ThetaMethod::new(1.0, ThetaVariant::Midpoint).unwrap());
+    let res = model.simulate(0.0, 0.0, 0.02, 30).unwrap();
+    assert_eq!(res.integrators.len(), 2);
+    for integrator in &res.integrators {
+        for (i, record) in res.records.iter().enumerate() {
+            approx_eq(integrator.yy[i], record.y_be, 1e-8);
+            approx_eq(integrator.ctm[i], record.ctm, 1e-6);
+        }
+    }
+}
+
+#[test]
+fn test_theta_method_tangents_match_finite_differences() {
+    let params = HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]);
+    let mut model = Model::new(ModelType::HardeningSoftening, params, Params::new(Method::DoPri5)).unwrap();
+    let (ddx, nd) = (0.05, 12);
+    let res = model.simulate(0.0, 0.0, ddx, nd).unwrap();
+    let h = 1e-5;
+    for mut scheme in all_schemes() {
+        for i in 1..nd + 1 {
+            // start from the backward Euler path to visit the hardening and softening regions
+            let (x0, y0) = (res.records[i - 1].x, res.records[i - 1].y_be);
+            let (mut x, mut y) = (x0, y0);
+            let ctm = model.local_update(&mut scheme, &mut x, &mut y, ddx).unwrap();
+            let (mut xa, mut ya) = (x0, y0);
+            let (mut xb, mut yb) = (x0, y0);
+            model.local_update(&mut scheme, &mut xa, &mut ya, ddx - h).unwrap();
+            model.local_update(&mut scheme, &mut xb, &mut yb, ddx + h).unwrap();
+            approx_eq(ctm, (yb - ya) / (2.0 * h), 1e-5);
+        }
+    }
+}
+
+#[test]
+fn test_theta_method_accuracy_and_stability() {
+    // Accuracy: halving Δx divides the error by 2^p
+    let lambda = 5.0;
+    let x_end = 1.0;
+    for scheme in all_schemes() {
+        let mut errors = Vec::new();
+        for nd in [40, 80] {
+            let mut model = Model::new(
+                ModelType::Dahlquist,
+                HashMap::from([("lambda", lambda)]),
+                Params::new(Method::DoPri5),
+            )
+            .unwrap();
+            model.add_integrator(scheme);
+            let res = model.simulate(0.0, 1.0, x_end / (nd as f64), nd).unwrap();
+            let y_end = *res.integrators[0].yy.last().unwrap();
+            errors.push(f64::abs(y_end - Dahlquist::analytical_y(lambda, x_end)));
+        }
+        let order = f64::log2(errors[0] / errors[1]);
+        println!("{:>36}: order = {:.3}", scheme.name(), order);
+        let expected = if scheme.theta() == 0.5 { 2.0 } else { 1.0 };
+        assert!(f64::abs(order - expected) < 0.15);
+    }
+
+    // Stability: with λ Δx = 5, the solution decays for θ ≥ 1/2 and grows for θ < 1/2
+    let ddx = 5.0 / lambda;
+    for theta in [0.0, 0.25, 0.5, 0.75, 1.0] {
+        let mut model = Model::new(
+            ModelType::Dahlquist,
+            HashMap::from([("lambda", lambda)]),
+            Params::new(Method::DoPri5),
+        )
+        .unwrap();
+        model.add_integrator(ThetaMethod::new(theta, ThetaVariant::Trapezoidal).unwrap());
+        let res = model.simulate(0.0, 1.0, ddx, 10).unwrap();
+        let y_end = f64::abs(*res.integrators[0].yy.last().unwrap());
+        println!("θ = {:.2}: |y(x = {})| = {:.2e}", theta, 10.0 * ddx, y_end);
+        assert_eq!(y_end < 1.0, theta >= 0.5);
+    }
+}
+
+#[test]
+fn test_theta_method_simulate_reports_all_schemes() {
+    let params = HashMap::from([("li", 10.0), ("lr", 3.0), ("y0r", 1.0), ("a", 3.0), ("b", 5.0)]);
+    let mut model = Model::new(ModelType::HardeningSoftening, params, Params::new(Method::DoPri5)).unwrap();
+    for scheme in all_schemes() {
+        model.add_integrator(scheme);
+    }
+    let res = model.simulate(0.0, 0.0, 0.02, 40).unwrap();
+    assert_eq!(res.integrators.len(), 6);
+    let cn = res.integrator("θ-method (Trapezoidal, θ = 0.5)").unwrap();
+    assert_eq!(cn.yy.len(), res.len());
+    assert_eq!(cn.ctm[0], res.records[0].com);
+
+    // The second-order schemes are closer to the ODE solution than backward Euler
+    let last = res.records.last().unwrap();
+    let error_be = f64::abs(last.y_be - last.y_ode);
+    for name in ["θ-method (Trapezoidal, θ = 0.5)", "θ-method (Midpoint, θ = 0.5)"] {
+        let error = f64::abs(res.integrator(name).unwrap().yy.last().unwrap() - last.y_ode);
+        assert!(error < error_be);
+    }
+
+    // Plot the results
+    if SAVE_FIGURE {
+        let xx = res.xx();
+        let mut plot = Plot::new();
+        for integrator in &res.integrators {
+            let mut curve = Curve::new();
+            curve.set_label(&integrator.name).draw(&xx, &integrator.ctm);
+            plot.add(&curve);
+        }
+        plot.grid_and_labels("x", "CTM")
+            .legend()
+            .save("/tmp/consistent_tangent/test_theta_method.svg")
+            .unwrap();
+    }
+}